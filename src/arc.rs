@@ -0,0 +1,152 @@
+use super::*;
+
+/// The number of segments a curve is split into before refining.
+///
+/// This keeps closed curves, e.g. `Circle`, from being mistaken for a point.
+const ARC_SEGMENTS: usize = 16;
+
+/// The maximum number of times a segment is split in two.
+const ARC_DEPTH: usize = 24;
+
+/// Computes the arc length of a 1D homotopy within tolerance `eps`, for some input `x`.
+///
+/// This uses adaptive quadrature, splitting segments until their chords agree.
+pub fn arc_length<H, X, F>(h: &H, x: X, eps: f64) -> f64
+    where F: Float, H: Homotopy<X, F>, H::Y: Distance, X: Clone
+{
+    let mut length = 0.0;
+    arc_segments(h, x, (eps, f64::INFINITY), |_, len| length += len);
+    length
+}
+
+/// Computes the arc length of a 1D homotopy within tolerance `eps`, for default input.
+pub fn arc_lengthu<H, X, F>(h: &H, eps: f64) -> f64
+    where F: Float, H: Homotopy<X, F>, H::Y: Distance, X: Clone + Default
+{
+    arc_length(h, X::default(), eps)
+}
+
+/// Visits the segments of a curve with the parameter at the end and the length.
+///
+/// The tolerance is a pair of the total length and of the speed within each segment.
+/// Parameters are computed in `f64` and rounded to the scalar type of the homotopy.
+fn arc_segments<H, X, F, V>(h: &H, x: X, (eps, speed): (f64, f64), mut visit: V)
+    where F: Float, H: Homotopy<X, F>, H::Y: Distance, X: Clone, V: FnMut(f64, f64)
+{
+    let eps = (eps / ARC_SEGMENTS as f64, speed);
+    let mut a = 0.0;
+    let mut ya = h.f(x.clone());
+    for i in 1..=ARC_SEGMENTS {
+        let b = i as f64 / ARC_SEGMENTS as f64;
+        let yb = if i == ARC_SEGMENTS {h.g(x.clone())} else {h.h(x.clone(), F::from_f64(b))};
+        arc_refine(h, &x, (a, &ya), (b, &yb), eps, ARC_DEPTH, &mut visit);
+        a = b;
+        ya = yb;
+    }
+}
+
+/// Splits a segment until the two halves agree with the chord within tolerance `eps.0`,
+/// and with each other within tolerance `eps.1`.
+///
+/// The error of the chord quarters with each split,
+/// so the length is extrapolated from the difference.
+/// The tolerance of the length is halved with each split, since the errors add up.
+fn arc_refine<H, X, F, V>(
+    h: &H,
+    x: &X,
+    (a, ya): (f64, &H::Y),
+    (b, yb): (f64, &H::Y),
+    eps: (f64, f64),
+    depth: usize,
+    visit: &mut V
+)
+    where F: Float, H: Homotopy<X, F>, H::Y: Distance, X: Clone, V: FnMut(f64, f64)
+{
+    let m = 0.5 * (a + b);
+    let ym = h.h(x.clone(), F::from_f64(m));
+    let (left, right) = (ya.distance(&ym), ym.distance(yb));
+    let diff = left + right - ya.distance(yb);
+    if depth == 0 || diff.abs() <= eps.0 && (left - right).abs() <= eps.1 {
+        // Visit both halves, such that the midpoint is kept in the table.
+        let scale = if left + right > 0.0 {1.0 + diff / (3.0 * (left + right))} else {0.0};
+        visit(m, left * scale);
+        visit(b, right * scale);
+    } else {
+        arc_refine(h, x, (a, ya), (m, &ym), (0.5 * eps.0, eps.1), depth - 1, visit);
+        arc_refine(h, x, (m, &ym), (b, yb), (0.5 * eps.0, eps.1), depth - 1, visit);
+    }
+}
+
+/// Reparametrizes a 1D homotopy to constant speed, such that `h(x, s)` is at the fraction `s`
+/// of the arc length.
+///
+/// The arc length is computed for some input when created, see `arc_length`,
+/// and stored in a lookup table that maps between the fraction and the parameter.
+/// The table is refined until the position is within the tolerance for that input.
+/// At `0.0` and `1.0` the homotopy is called directly, such that `f` and `g` are kept exactly.
+#[derive(Clone)]
+pub struct ArcLength<H> {
+    shape: H,
+    // The parameter and the fraction of the arc length at the end of each segment.
+    table: Vec<(f64, f64)>,
+    length: f64,
+}
+
+impl<H> ArcLength<H> {
+    /// Creates a new constant speed homotopy, within tolerance `eps` for some input `x`.
+    pub fn new<X, F>(shape: H, x: X, eps: f64) -> Self
+        where F: Float, H: Homotopy<X, F>, H::Y: Distance, X: Clone
+    {
+        let mut table = vec![(0.0, 0.0)];
+        let mut length = 0.0;
+        arc_segments(&shape, x, (eps, eps), |s, len| {
+            length += len;
+            table.push((s, length));
+        });
+        for entry in &mut table {
+            entry.1 = if length > 0.0 {entry.1 / length} else {entry.0};
+        }
+        ArcLength {shape, table, length}
+    }
+
+    /// Gets the 1D homotopy.
+    pub fn shape(&self) -> &H {&self.shape}
+
+    /// Gets the arc length.
+    pub fn length(&self) -> f64 {self.length}
+
+    /// Gets the parameter of the homotopy at some fraction of the arc length.
+    pub fn param<F: Float>(&self, fraction: F) -> F {
+        lookup(&self.table, fraction, |e| (e.1, e.0))
+    }
+
+    /// Gets the fraction of the arc length at some parameter of the homotopy.
+    ///
+    /// This is the inverse of `ArcLength::param`.
+    pub fn fraction<F: Float>(&self, param: F) -> F {lookup(&self.table, param, |e| e)}
+}
+
+/// Looks up a value in a sorted table, interpolating linearly between entries.
+///
+/// The interpolation is computed in the scalar type of the value.
+fn lookup<F, K>(table: &[(f64, f64)], v: F, key: K) -> F
+    where F: Float, K: Fn((f64, f64)) -> (f64, f64)
+{
+    let i = table.partition_point(|&e| key(e).0 < v.to_f64()).clamp(1, table.len() - 1);
+    let ((a, fa), (b, fb)) = (key(table[i - 1]), key(table[i]));
+    let c = F::from_f64;
+    if b > a {c(fa) + (v - c(a)) / c(b - a) * c(fb - fa)} else {c(fa)}
+}
+
+impl<X, H, F> Homotopy<X, F> for ArcLength<H>
+    where F: Float, H: Homotopy<X, F>
+{
+    type Y = H::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.shape.f(x)}
+    fn g(&self, x: X) -> Self::Y {self.shape.g(x)}
+    fn h(&self, x: X, s: F) -> Self::Y {
+        if s == F::zero() || s == F::one() {return self.shape.h(x, s)};
+        self.shape.h(x, self.param(s))
+    }
+}
@@ -0,0 +1,211 @@
+use super::*;
+
+use std::fmt;
+
+/// Equality within some tolerance.
+///
+/// This is used to check homotopy constraints for floating-point shapes,
+/// where exact equality is too strict.
+pub trait ApproxEq {
+    /// Returns `true` if the difference between the values is at most `eps`.
+    fn approx_eq(&self, other: &Self, eps: f64) -> bool;
+}
+
+impl ApproxEq for f64 {
+    fn approx_eq(&self, other: &f64, eps: f64) -> bool {
+        self == other || (self - other).abs() <= eps
+    }
+}
+
+impl ApproxEq for f32 {
+    fn approx_eq(&self, other: &f32, eps: f64) -> bool {
+        self == other || ((self - other).abs() as f64) <= eps
+    }
+}
+
+impl ApproxEq for () {
+    fn approx_eq(&self, _: &(), _: f64) -> bool {true}
+}
+
+impl ApproxEq for bool {
+    fn approx_eq(&self, other: &bool, _: f64) -> bool {self == other}
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+    fn approx_eq(&self, other: &[T; N], eps: f64) -> bool {
+        self.iter().zip(other.iter()).all(|(a, b)| a.approx_eq(b, eps))
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    fn approx_eq(&self, other: &Vec<T>, eps: f64) -> bool {
+        self.len() == other.len() &&
+        self.iter().zip(other.iter()).all(|(a, b)| a.approx_eq(b, eps))
+    }
+}
+
+impl<A: ApproxEq, B: ApproxEq> ApproxEq for (A, B) {
+    fn approx_eq(&self, other: &(A, B), eps: f64) -> bool {
+        self.0.approx_eq(&other.0, eps) &&
+        self.1.approx_eq(&other.1, eps)
+    }
+}
+
+impl<A: ApproxEq, B: ApproxEq, C: ApproxEq> ApproxEq for (A, B, C) {
+    fn approx_eq(&self, other: &(A, B, C), eps: f64) -> bool {
+        self.0.approx_eq(&other.0, eps) &&
+        self.1.approx_eq(&other.1, eps) &&
+        self.2.approx_eq(&other.2, eps)
+    }
+}
+
+impl<A: ApproxEq, B: ApproxEq, C: ApproxEq, D: ApproxEq> ApproxEq for (A, B, C, D) {
+    fn approx_eq(&self, other: &(A, B, C, D), eps: f64) -> bool {
+        self.0.approx_eq(&other.0, eps) &&
+        self.1.approx_eq(&other.1, eps) &&
+        self.2.approx_eq(&other.2, eps) &&
+        self.3.approx_eq(&other.3, eps)
+    }
+}
+
+/// A homotopy constraint that failed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CheckFailure<Y> {
+    /// The side where the constraint failed.
+    ///
+    /// Fixed axes are `Some(0.0)` or `Some(1.0)` and free axes are `None`.
+    /// When all axes are free, the constraint failed for the homotopy itself.
+    pub side: Vec<Option<f64>>,
+    /// Whether the constraint failed at the end corner of the side,
+    /// otherwise at the start corner.
+    pub end: bool,
+    /// The parameter passed to `h`.
+    pub params: Vec<f64>,
+    /// The output of `h`.
+    pub observed: Y,
+    /// The expected output, from `f`, `g` or the corner of a side.
+    pub expected: Y,
+}
+
+impl<Y: fmt::Debug> fmt::Display for CheckFailure<Y> {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        write!(w, "side [")?;
+        for (i, v) in self.side.iter().enumerate() {
+            if i > 0 {write!(w, ", ")?};
+            match *v {
+                None => write!(w, "_")?,
+                Some(v) => write!(w, "{:?}", v)?,
+            }
+        }
+        write!(w, "] failed at {} corner {:?}: observed {:?}, expected {:?}",
+            if self.end {"end"} else {"start"}, self.params, self.observed, self.expected)
+    }
+}
+
+/// Reports which homotopy constraints failed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CheckReport<Y> {
+    /// The constraints that failed.
+    pub failures: Vec<CheckFailure<Y>>,
+}
+
+impl<Y> CheckReport<Y> {
+    /// Returns `true` if no constraints failed.
+    pub fn is_ok(&self) -> bool {self.failures.is_empty()}
+}
+
+impl<Y: fmt::Debug> fmt::Display for CheckReport<Y> {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_ok() {return write!(w, "ok")};
+        for (i, failure) in self.failures.iter().enumerate() {
+            if i > 0 {writeln!(w)?};
+            write!(w, "{}", failure)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks that the homotopy constraints hold within tolerance `eps` for some input `x`.
+///
+/// This works for any dimension, see `checkn`.
+#[must_use]
+pub fn check_approx<H, X, S>(h: &H, x: X, eps: f64) -> bool
+    where H: Homotopy<X, S>,
+          H::Y: ApproxEq,
+          X: Clone,
+          S: Param,
+{
+    visit_sides(h, || x.clone(), |_, _, _, observed, expected| observed.approx_eq(&expected, eps))
+}
+
+/// Checks that the homotopy constraints hold within tolerance `eps` for default input.
+#[must_use]
+pub fn checku_approx<H, X, S>(h: &H, eps: f64) -> bool
+    where H: Homotopy<X, S>,
+          H::Y: ApproxEq,
+          X: Clone + Default,
+          S: Param,
+{
+    check_approx(h, X::default(), eps)
+}
+
+/// Checks the homotopy constraints within tolerance `eps` for some input `x`,
+/// reporting every constraint that failed.
+pub fn check_report<H, X, S>(h: &H, x: X, eps: f64) -> CheckReport<H::Y>
+    where H: Homotopy<X, S>,
+          H::Y: ApproxEq,
+          X: Clone,
+          S: Param,
+{
+    let mut failures = vec![];
+    visit_sides(h, || x.clone(), |side, end, s, observed, expected| {
+        if !observed.approx_eq(&expected, eps) {
+            failures.push(CheckFailure {
+                side: side.to_vec(),
+                end,
+                params: (0..S::DIM).map(|i| s.get(i).to_f64()).collect(),
+                observed,
+                expected,
+            });
+        }
+        true
+    });
+    CheckReport {failures}
+}
+
+/// Checks the homotopy constraints within tolerance `eps` for default input,
+/// reporting every constraint that failed.
+pub fn checku_report<H, X, S>(h: &H, eps: f64) -> CheckReport<H::Y>
+    where H: Homotopy<X, S>,
+          H::Y: ApproxEq,
+          X: Clone + Default,
+          S: Param,
+{
+    check_report(h, X::default(), eps)
+}
+
+/// Two homotopies that should agree at some parameter, but do not.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch<Y> {
+    /// The parameter where they should agree, in the parameter space of the result.
+    pub params: Vec<f64>,
+    /// The output of the first homotopy.
+    pub first: Y,
+    /// The output of the second homotopy.
+    pub second: Y,
+}
+
+impl<Y: fmt::Debug> fmt::Display for Mismatch<Y> {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        write!(w, "mismatch at {:?}: {:?} != {:?}", self.params, self.first, self.second)
+    }
+}
+
+impl<Y: fmt::Debug> std::error::Error for Mismatch<Y> {}
+
+/// Returns an error if two outputs are not equal within tolerance `eps`.
+pub fn expect_approx<Y: ApproxEq>(params: Vec<f64>, first: Y, second: Y, eps: f64)
+-> Result<(), Mismatch<Y>> {
+    if first.approx_eq(&second, eps) {Ok(())}
+    else {Err(Mismatch {params, first, second})}
+}
@@ -0,0 +1,232 @@
+use super::*;
+
+/// Distance between two outputs of a homotopy.
+pub trait Distance {
+    /// Returns the distance between the values.
+    fn distance(&self, other: &Self) -> f64;
+}
+
+impl Distance for f64 {
+    fn distance(&self, other: &f64) -> f64 {(self - other).abs()}
+}
+
+impl Distance for f32 {
+    fn distance(&self, other: &f32) -> f64 {(self - other).abs() as f64}
+}
+
+impl Distance for () {
+    fn distance(&self, _: &()) -> f64 {0.0}
+}
+
+impl Distance for bool {
+    fn distance(&self, other: &bool) -> f64 {if self == other {0.0} else {1.0}}
+}
+
+impl<T: Distance, const N: usize> Distance for [T; N] {
+    fn distance(&self, other: &[T; N]) -> f64 {
+        self.iter().zip(other.iter()).map(|(a, b)| a.distance(b).powi(2)).sum::<f64>().sqrt()
+    }
+}
+
+impl<T: Distance> Distance for Vec<T> {
+    fn distance(&self, other: &Vec<T>) -> f64 {
+        if self.len() != other.len() {return f64::INFINITY};
+        self.iter().zip(other.iter()).map(|(a, b)| a.distance(b).powi(2)).sum::<f64>().sqrt()
+    }
+}
+
+impl<A: Distance, B: Distance> Distance for (A, B) {
+    fn distance(&self, other: &(A, B)) -> f64 {
+        (self.0.distance(&other.0).powi(2) +
+         self.1.distance(&other.1).powi(2)).sqrt()
+    }
+}
+
+impl<A: Distance, B: Distance, C: Distance> Distance for (A, B, C) {
+    fn distance(&self, other: &(A, B, C)) -> f64 {
+        (self.0.distance(&other.0).powi(2) +
+         self.1.distance(&other.1).powi(2) +
+         self.2.distance(&other.2).powi(2)).sqrt()
+    }
+}
+
+impl<A: Distance, B: Distance, C: Distance, D: Distance> Distance for (A, B, C, D) {
+    fn distance(&self, other: &(A, B, C, D)) -> f64 {
+        (self.0.distance(&other.0).powi(2) +
+         self.1.distance(&other.1).powi(2) +
+         self.2.distance(&other.2).powi(2) +
+         self.3.distance(&other.3).powi(2)).sqrt()
+    }
+}
+
+/// A suspected jump, where a homotopy is not continuous.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Jump {
+    /// The parameter at one side of the jump.
+    pub from: Vec<f64>,
+    /// The parameter at the other side of the jump.
+    pub to: Vec<f64>,
+    /// The distance between the outputs at each side of the jump.
+    pub distance: f64,
+}
+
+/// Reports the continuity of a homotopy, estimated by sampling.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContinuityReport {
+    /// Estimated Lipschitz bound, the largest distance divided by parameter distance
+    /// between neighbor samples.
+    ///
+    /// Samples across suspected jumps are excluded.
+    pub lipschitz: f64,
+    /// Estimated modulus of continuity, the largest distance between neighbor samples.
+    ///
+    /// Samples across suspected jumps are excluded.
+    pub modulus: f64,
+    /// The suspected jumps.
+    pub jumps: Vec<Jump>,
+}
+
+impl ContinuityReport {
+    /// Returns `true` if there are no suspected jumps.
+    pub fn is_continuous(&self) -> bool {self.jumps.is_empty()}
+}
+
+/// Samples a homotopy across the parameter cube to verify continuity.
+///
+/// Neighbor samples on a grid are compared along every axis.
+/// When the distance is larger than the tolerance,
+/// the interval between them is bisected toward the largest distance.
+/// If the distance is still larger than the tolerance after bisecting
+/// and has stopped shrinking with the interval, the interval is reported as a jump.
+/// The distance of a continuous homotopy keeps shrinking, e.g. halving with the interval
+/// when Lipschitz continuous, such that steep homotopies are not mistaken for jumps.
+#[derive(Copy, Clone, Debug)]
+pub struct Continuity {
+    /// The number of grid steps per axis.
+    pub resolution: usize,
+    /// The number of bisections of intervals.
+    pub depth: usize,
+    /// The largest distance that is never reported as a jump.
+    pub tolerance: f64,
+}
+
+impl Default for Continuity {
+    fn default() -> Continuity {
+        Continuity {
+            resolution: 16,
+            depth: 32,
+            tolerance: 1e-6,
+        }
+    }
+}
+
+impl Continuity {
+    /// Verifies continuity of a homotopy for some input `x`.
+    pub fn check<H, X, S>(&self, h: &H, x: X) -> ContinuityReport
+        where H: Homotopy<X, S>,
+              H::Y: Distance,
+              X: Clone,
+              S: Param,
+    {
+        let n = S::DIM;
+        let res = self.resolution.max(1);
+        let step = 1.0 / res as f64;
+        let mut report = ContinuityReport {lipschitz: 0.0, modulus: 0.0, jumps: vec![]};
+        for ind in 0..(res + 1).pow(n as u32) {
+            let digit = |axis: usize| ind / (res + 1).pow(axis as u32) % (res + 1);
+            let p = S::from_fn(|i| S::Float::from_f64(digit(i) as f64 * step));
+            let yp = h.h(x.clone(), p);
+            for axis in 0..n {
+                if digit(axis) == res {continue};
+
+                let q = S::from_fn(|i| {
+                    if i == axis {S::Float::from_f64((digit(i) + 1) as f64 * step)} else {p.get(i)}
+                });
+                let yq = h.h(x.clone(), q);
+                let d = yp.distance(&yq);
+                if d > self.tolerance {
+                    if let Some(jump) = self.bisect(h, x.clone(), p, q, d) {
+                        report.jumps.push(jump);
+                        continue;
+                    }
+                }
+                report.modulus = report.modulus.max(d);
+                report.lipschitz = report.lipschitz.max(d / step);
+            }
+        }
+        report
+    }
+
+    /// Verifies continuity of a homotopy for default input.
+    pub fn checku<H, X, S>(&self, h: &H) -> ContinuityReport
+        where H: Homotopy<X, S>,
+              H::Y: Distance,
+              X: Clone + Default,
+              S: Param,
+    {
+        self.check(h, X::default())
+    }
+
+    fn bisect<H, X, S>(&self, h: &H, x: X, mut a: S, mut b: S, mut d: f64) -> Option<Jump>
+        where H: Homotopy<X, S>,
+              H::Y: Distance,
+              X: Clone,
+              S: Param,
+    {
+        let mut ya = h.h(x.clone(), a);
+        let mut yb = h.h(x.clone(), b);
+        // The distance after each bisection, starting with the initial distance.
+        let mut ds = vec![d];
+        for _ in 0..self.depth {
+            if d <= self.tolerance {return None};
+
+            let m = S::from_fn(|i| S::Float::from_f64(0.5) * (a.get(i) + b.get(i)));
+            // Stop at the precision of the parameter.
+            let same = |p: S| (0..S::DIM).all(|i| m.get(i) == p.get(i));
+            if same(a) || same(b) {break};
+            let ym = h.h(x.clone(), m);
+            let d1 = ya.distance(&ym);
+            let d2 = ym.distance(&yb);
+            if d1 >= d2 {
+                b = m;
+                yb = ym;
+                d = d1;
+            } else {
+                a = m;
+                ya = ym;
+                d = d2;
+            }
+            ds.push(d);
+        }
+        if d <= self.tolerance {return None};
+        // The distance shrinks with the interval when continuous,
+        // so it is compared to the distance half way through the bisections.
+        let n = ds.len() - 1;
+        if n >= 2 && d <= 0.5 * ds[n / 2] {return None};
+        Some(Jump {
+            from: (0..S::DIM).map(|i| a.get(i).to_f64()).collect(),
+            to: (0..S::DIM).map(|i| b.get(i).to_f64()).collect(),
+            distance: d,
+        })
+    }
+}
+
+/// Verifies continuity of a homotopy for some input `x`, using default settings.
+pub fn check_continuity<H, X, S>(h: &H, x: X) -> ContinuityReport
+    where H: Homotopy<X, S>,
+          H::Y: Distance,
+          X: Clone,
+          S: Param,
+{
+    Continuity::default().check(h, x)
+}
+
+/// Verifies continuity of a homotopy for default input, using default settings.
+pub fn checku_continuity<H, X, S>(h: &H) -> ContinuityReport
+    where H: Homotopy<X, S>,
+          H::Y: Distance,
+          X: Clone + Default,
+          S: Param,
+{
+    Continuity::default().checku(h)
+}
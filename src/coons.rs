@@ -0,0 +1,89 @@
+use super::*;
+
+/// A Coons patch, filling a 2D homotopy from its four sides.
+///
+/// The sides follow the same conventions as `Left`, `Right`, `Top` and `Bottom`,
+/// e.g. the left side goes from `[0.0, 0.0]` to `[0.0, 1.0]`
+/// and the top side goes from `[0.0, 0.0]` to `[1.0, 0.0]`.
+///
+/// The inside is a bilinear blend of the sides, and the sides are reproduced exactly.
+#[derive(Copy, Clone)]
+pub struct Coons<L, R, T, B> {
+    pub(crate) left: L,
+    pub(crate) right: R,
+    pub(crate) top: T,
+    pub(crate) bottom: B,
+}
+
+impl<L, R, T, B> Coons<L, R, T, B> {
+    /// Creates a new Coons patch from the left, right, top and bottom sides.
+    ///
+    /// The corners are not checked, see `Coons::check_corners`.
+    pub fn new(left: L, right: R, top: T, bottom: B) -> Self {
+        Coons {left, right, top, bottom}
+    }
+
+    /// Creates a new Coons patch, checking that the corners agree within tolerance `eps`
+    /// for some input `x`.
+    pub fn try_new<X, Y>(left: L, right: R, top: T, bottom: B, x: X, eps: f64)
+    -> Result<Self, Mismatch<Y>>
+        where L: Homotopy<X, Y = Y>,
+              R: Homotopy<X, Y = Y>,
+              T: Homotopy<X, Y = Y>,
+              B: Homotopy<X, Y = Y>,
+              Y: ApproxEq,
+              X: Clone,
+    {
+        let coons = Coons::new(left, right, top, bottom);
+        coons.check_corners(x, eps)?;
+        Ok(coons)
+    }
+
+    /// Checks that the sides agree at the corners within tolerance `eps` for some input `x`.
+    ///
+    /// Returns the first corner where they do not agree.
+    pub fn check_corners<X, Y>(&self, x: X, eps: f64) -> Result<(), Mismatch<Y>>
+        where L: Homotopy<X, Y = Y>,
+              R: Homotopy<X, Y = Y>,
+              T: Homotopy<X, Y = Y>,
+              B: Homotopy<X, Y = Y>,
+              Y: ApproxEq,
+              X: Clone,
+    {
+        expect_approx(vec![0.0, 0.0], self.left.f(x.clone()), self.top.f(x.clone()), eps)?;
+        expect_approx(vec![0.0, 1.0], self.left.g(x.clone()), self.bottom.f(x.clone()), eps)?;
+        expect_approx(vec![1.0, 0.0], self.right.f(x.clone()), self.top.g(x.clone()), eps)?;
+        expect_approx(vec![1.0, 1.0], self.right.g(x.clone()), self.bottom.g(x), eps)
+    }
+}
+
+impl<X, Y, F, L, R, T, B> Homotopy<X, [F; 2]> for Coons<L, R, T, B>
+    where L: Homotopy<X, F, Y = Y>,
+          R: Homotopy<X, F, Y = Y>,
+          T: Homotopy<X, F, Y = Y>,
+          B: Homotopy<X, F, Y = Y>,
+          F: Float,
+          Y: VectorSpace<Scalar = F>,
+          X: Clone,
+{
+    type Y = Y;
+
+    fn f(&self, x: X) -> Y {self.left.f(x)}
+    fn g(&self, x: X) -> Y {self.right.g(x)}
+    fn h(&self, x: X, s: [F; 2]) -> Y {
+        let [u, v] = s;
+        // Use the sides directly to reproduce them exactly.
+        if u == F::zero() {return self.left.h(x, v)}
+        else if u == F::one() {return self.right.h(x, v)}
+        else if v == F::zero() {return self.top.h(x, u)}
+        else if v == F::one() {return self.bottom.h(x, u)};
+
+        let left = self.left.h(x.clone(), v);
+        let right = self.right.h(x.clone(), v);
+        let top = self.top.h(x.clone(), u);
+        let bottom = self.bottom.h(x.clone(), u);
+        let corners = self.left.f(x.clone()).lerp(&self.right.f(x.clone()), u)
+            .lerp(&self.left.g(x.clone()).lerp(&self.right.g(x), u), v);
+        left.lerp(&right, u).add_vector(&top.lerp(&bottom, v)).sub_vector(&corners)
+    }
+}
@@ -0,0 +1,112 @@
+use super::*;
+
+/// The tolerance below which the speed or the turning of a curve is treated as zero.
+///
+/// The turning is the change of direction per unit of the parameter,
+/// which is the curvature times the speed.
+pub const CURVE_EPS: f64 = 1e-9;
+
+/// The Frenet frame of a 2D or 3D curve at some point, see `frenet`.
+///
+/// 2D curves are embedded in the plane `z = 0`,
+/// such that the binormal is `[0.0, 0.0, 1.0]` when turning counter-clockwise
+/// and `[0.0, 0.0, -1.0]` when turning clockwise.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Frenet {
+    /// The unit tangent, pointing in the direction of motion.
+    pub tangent: [f64; 3],
+    /// The unit normal, pointing towards the center of curvature.
+    pub normal: [f64; 3],
+    /// The unit binormal, which is the cross product of the tangent and the normal.
+    pub binormal: [f64; 3],
+    /// The curvature, which is the inverse of the radius of the osculating circle.
+    pub curvature: f64,
+    /// The torsion, which is how fast the curve twists out of the osculating plane.
+    pub torsion: f64,
+}
+
+/// Gets the unit tangent of a 2D or 3D curve, embedded in 3D like `Frenet`.
+///
+/// Returns `None` when the speed is zero, e.g. at a cusp.
+pub fn unit_tangent<H, X, const N: usize>(h: &H, x: X, s: f64) -> Option<[f64; 3]>
+    where for<'a> Derivative<&'a H>: Homotopy<X, Y = [f64; N]>
+{
+    let r1 = embed(diff(h).h(x, s));
+    let speed = norm(r1);
+    if speed <= CURVE_EPS {None} else {Some(scale(r1, 1.0 / speed))}
+}
+
+/// Gets the curvature of a 2D or 3D curve, which is `0.0` when straight.
+///
+/// For example, the curvature of a circle is the inverse of the radius.
+/// Returns `None` when the speed is zero, e.g. at a cusp.
+pub fn curvature<H, X, const N: usize>(h: &H, x: X, s: f64) -> Option<f64>
+    where for<'a> Derivative<&'a H>: Homotopy<X, Y = [f64; N]>,
+          for<'a> Derivative<Derivative<&'a H>>: Homotopy<X, Y = [f64; N]>,
+          X: Clone,
+{
+    let r1 = embed(diff(h).h(x.clone(), s));
+    let r2 = embed(diff(diff(h)).h(x, s));
+    let speed = norm(r1);
+    if speed <= CURVE_EPS {None} else {Some(norm(cross(r1, r2)) / speed.powi(3))}
+}
+
+/// Gets the torsion of a 2D or 3D curve, which is `0.0` for plane curves.
+///
+/// Returns `None` when the speed or the curvature is zero, since the torsion is undefined.
+pub fn torsion<H, X, const N: usize>(h: &H, x: X, s: f64) -> Option<f64>
+    where for<'a> Derivative<&'a H>: Homotopy<X, Y = [f64; N]>,
+          for<'a> Derivative<Derivative<&'a H>>: Homotopy<X, Y = [f64; N]>,
+          for<'a> Derivative<Derivative<Derivative<&'a H>>>: Homotopy<X, Y = [f64; N]>,
+          X: Clone,
+{
+    frenet(h, x, s).map(|frame| frame.torsion)
+}
+
+/// Gets the Frenet frame of a 2D or 3D curve, using exact derivatives where available.
+///
+/// Returns `None` when the speed or the curvature is zero,
+/// e.g. at a cusp or along a straight line, since the normal is undefined.
+pub fn frenet<H, X, const N: usize>(h: &H, x: X, s: f64) -> Option<Frenet>
+    where for<'a> Derivative<&'a H>: Homotopy<X, Y = [f64; N]>,
+          for<'a> Derivative<Derivative<&'a H>>: Homotopy<X, Y = [f64; N]>,
+          for<'a> Derivative<Derivative<Derivative<&'a H>>>: Homotopy<X, Y = [f64; N]>,
+          X: Clone,
+{
+    let r1 = embed(diff(h).h(x.clone(), s));
+    let r2 = embed(diff(diff(h)).h(x.clone(), s));
+    let r3 = embed(diff(diff(diff(h))).h(x, s));
+    let speed = norm(r1);
+    let c = cross(r1, r2);
+    let turning = norm(c);
+    if speed <= CURVE_EPS || turning <= CURVE_EPS * speed * speed {return None};
+
+    let tangent = scale(r1, 1.0 / speed);
+    let binormal = scale(c, 1.0 / turning);
+    Some(Frenet {
+        tangent,
+        normal: cross(binormal, tangent),
+        binormal,
+        curvature: turning / speed.powi(3),
+        torsion: dot(c, r3) / (turning * turning),
+    })
+}
+
+/// Wraps in a derivative, since a type alias can not be called.
+fn diff<T>(t: T) -> Derivative<T> {Partial(t)}
+
+/// Embeds a 2D or 3D point in 3D.
+pub(crate) fn embed<const N: usize>(v: [f64; N]) -> [f64; 3] {
+    assert!(N == 2 || N == 3, "expected 2D or 3D points, found {}D", N);
+    std::array::from_fn(|i| if i < N {v[i]} else {0.0})
+}
+
+pub(crate) fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {a[0] * b[0] + a[1] * b[1] + a[2] * b[2]}
+
+pub(crate) fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+pub(crate) fn norm(a: [f64; 3]) -> f64 {dot(a, a).sqrt()}
+
+pub(crate) fn scale(a: [f64; 3], s: f64) -> [f64; 3] {a.map(|v| v * s)}
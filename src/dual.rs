@@ -0,0 +1,669 @@
+use super::*;
+
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A dual number `re + du·ε` where `ε² = 0`, used for forward-mode automatic differentiation.
+///
+/// This is a `Float` scalar, so shapes can be evaluated with dual parameters,
+/// carrying the derivative along with the value.
+///
+/// Dual numbers are equal when both the value and the derivative are equal.
+/// This keeps special cases for exact values, e.g. `s == 1.0`, from dropping the derivative.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Dual<F> {
+    /// The value.
+    pub re: F,
+    /// The derivative.
+    pub du: F,
+}
+
+impl<F: Float> Dual<F> {
+    /// Creates a new dual number.
+    pub fn new(re: F, du: F) -> Self {Dual {re, du}}
+
+    /// Creates a variable with derivative `1.0`.
+    pub fn var(re: F) -> Self {Dual {re, du: F::one()}}
+
+    /// Creates a constant with derivative `0.0`.
+    pub fn constant(re: F) -> Self {Dual {re, du: F::zero()}}
+}
+
+impl<F: Float> PartialOrd for Dual<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.re.partial_cmp(&other.re) {
+            Some(Ordering::Equal) if self.du != other.du => None,
+            ord => ord,
+        }
+    }
+}
+
+impl<F: Float> Add for Dual<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {Dual {re: self.re + other.re, du: self.du + other.du}}
+}
+
+impl<F: Float> Sub for Dual<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {Dual {re: self.re - other.re, du: self.du - other.du}}
+}
+
+impl<F: Float> Mul for Dual<F> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Dual {re: self.re * other.re, du: self.re * other.du + self.du * other.re}
+    }
+}
+
+impl<F: Float> Div for Dual<F> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Dual {
+            re: self.re / other.re,
+            du: (self.du * other.re - self.re * other.du) / (other.re * other.re),
+        }
+    }
+}
+
+impl<F: Float> Neg for Dual<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {Dual {re: -self.re, du: -self.du}}
+}
+
+impl<F: Float> Float for Dual<F> {
+    fn from_f64(v: f64) -> Self {Dual::constant(F::from_f64(v))}
+    fn to_f64(self) -> f64 {self.re.to_f64()}
+    fn pi() -> Self {Dual::constant(F::pi())}
+
+    fn floor(self) -> Self {Dual::constant(self.re.floor())}
+    fn abs(self) -> Self {if self.re < F::zero() {-self} else {self}}
+    fn sqrt(self) -> Self {
+        let re = self.re.sqrt();
+        Dual {re, du: self.du / (F::from_f64(2.0) * re)}
+    }
+    fn sin(self) -> Self {Dual {re: self.re.sin(), du: self.re.cos() * self.du}}
+    fn cos(self) -> Self {Dual {re: self.re.cos(), du: -self.re.sin() * self.du}}
+    fn exp(self) -> Self {
+        let re = self.re.exp();
+        Dual {re, du: re * self.du}
+    }
+    fn powi(self, n: i32) -> Self {
+        if n == 0 {return Dual::constant(F::one())};
+        Dual {re: self.re.powi(n), du: F::from_f64(n as f64) * self.re.powi(n - 1) * self.du}
+    }
+}
+
+/// Lifts a value or a homotopy to dual numbers, such that it can be differentiated.
+///
+/// Values are lifted with derivative `0.0`, e.g. `f64` is lifted to `Dual<f64>`.
+/// Homotopies are lifted by lifting the values they hold,
+/// e.g. `Lerp<[f64; 2]>` is lifted to `Lerp<[Dual<f64>; 2]>`.
+pub trait Lift {
+    /// The lifted type.
+    type Output;
+
+    /// Lifts to dual numbers.
+    fn lift(&self) -> Self::Output;
+}
+
+/// A value of dual numbers, which is a value and a derivative of some base type.
+pub trait Tangent: Sized {
+    /// The base type, e.g. `f64` for `Dual<f64>`.
+    type Base;
+
+    /// Gets the value.
+    fn value(&self) -> Self::Base;
+    /// Gets the derivative.
+    fn tangent(&self) -> Self::Base;
+    /// Gets the value moved `t` along the derivative.
+    fn step(&self, t: f64) -> Self::Base;
+    /// Creates from a value and a derivative.
+    fn from_parts(value: Self::Base, tangent: Self::Base) -> Self;
+}
+
+impl<F: Float> Tangent for Dual<F> {
+    type Base = F;
+
+    fn value(&self) -> F {self.re}
+    fn tangent(&self) -> F {self.du}
+    fn step(&self, t: f64) -> F {self.re + F::from_f64(t) * self.du}
+    fn from_parts(re: F, du: F) -> Self {Dual {re, du}}
+}
+
+impl Tangent for () {
+    type Base = ();
+
+    fn value(&self) {}
+    fn tangent(&self) {}
+    fn step(&self, _: f64) {}
+    fn from_parts(_: (), _: ()) {}
+}
+
+impl<T: Tangent, const N: usize> Tangent for [T; N] {
+    type Base = [T::Base; N];
+
+    fn value(&self) -> Self::Base {std::array::from_fn(|i| self[i].value())}
+    fn tangent(&self) -> Self::Base {std::array::from_fn(|i| self[i].tangent())}
+    fn step(&self, t: f64) -> Self::Base {std::array::from_fn(|i| self[i].step(t))}
+    fn from_parts(value: Self::Base, tangent: Self::Base) -> Self {
+        let mut tangent = IntoIterator::into_iter(tangent);
+        value.map(|v| T::from_parts(v, tangent.next().unwrap()))
+    }
+}
+
+impl<T: Tangent> Tangent for Vec<T> {
+    type Base = Vec<T::Base>;
+
+    fn value(&self) -> Self::Base {self.iter().map(|a| a.value()).collect()}
+    fn tangent(&self) -> Self::Base {self.iter().map(|a| a.tangent()).collect()}
+    fn step(&self, t: f64) -> Self::Base {self.iter().map(|a| a.step(t)).collect()}
+    fn from_parts(value: Self::Base, tangent: Self::Base) -> Self {
+        assert_eq!(value.len(), tangent.len());
+        value.into_iter().zip(tangent).map(|(v, t)| T::from_parts(v, t)).collect()
+    }
+}
+
+macro_rules! dual_tuple {
+    ($($t:ident: $i:tt),*) => {
+        impl<$($t: Tangent),*> Tangent for ($($t,)*) {
+            type Base = ($($t::Base,)*);
+
+            fn value(&self) -> Self::Base {($(self.$i.value(),)*)}
+            fn tangent(&self) -> Self::Base {($(self.$i.tangent(),)*)}
+            fn step(&self, t: f64) -> Self::Base {($(self.$i.step(t),)*)}
+            fn from_parts(value: Self::Base, tangent: Self::Base) -> Self {
+                ($($t::from_parts(value.$i, tangent.$i),)*)
+            }
+        }
+
+        impl<$($t: Lift),*> Lift for ($($t,)*) {
+            type Output = ($($t::Output,)*);
+
+            fn lift(&self) -> Self::Output {($(self.$i.lift(),)*)}
+        }
+    }
+}
+
+dual_tuple!(A: 0, B: 1);
+dual_tuple!(A: 0, B: 1, C: 2);
+dual_tuple!(A: 0, B: 1, C: 2, D: 3);
+
+impl Lift for f64 {
+    type Output = Dual<f64>;
+
+    fn lift(&self) -> Dual<f64> {Dual::constant(*self)}
+}
+
+impl Lift for f32 {
+    type Output = Dual<f32>;
+
+    fn lift(&self) -> Dual<f32> {Dual::constant(*self)}
+}
+
+/// Lifts to nested dual numbers, which carry higher derivatives, see `Partial`.
+impl<F: Float> Lift for Dual<F> {
+    type Output = Dual<Dual<F>>;
+
+    fn lift(&self) -> Dual<Dual<F>> {Dual::constant(*self)}
+}
+
+impl Lift for () {
+    type Output = ();
+
+    fn lift(&self) {}
+}
+
+impl<T: Lift, const N: usize> Lift for [T; N] {
+    type Output = [T::Output; N];
+
+    fn lift(&self) -> Self::Output {std::array::from_fn(|i| self[i].lift())}
+}
+
+impl<T: Lift> Lift for Vec<T> {
+    type Output = Vec<T::Output>;
+
+    fn lift(&self) -> Self::Output {self.iter().map(|a| a.lift()).collect()}
+}
+
+impl<T: Lift> Lift for &T {
+    type Output = T::Output;
+
+    fn lift(&self) -> T::Output {T::lift(self)}
+}
+
+impl Lift for Id {
+    type Output = Id;
+
+    fn lift(&self) -> Id {Id}
+}
+
+impl<T: Lift> Lift for Lerp<T> {
+    type Output = Lerp<T::Output>;
+
+    fn lift(&self) -> Self::Output {Lerp(self.0.lift(), self.1.lift())}
+}
+
+impl<T: Lift> Lift for QuadraticBezier<T> {
+    type Output = QuadraticBezier<T::Output>;
+
+    fn lift(&self) -> Self::Output {QuadraticBezier(self.0.lift(), self.1.lift(), self.2.lift())}
+}
+
+impl<T: Lift> Lift for CubicBezier<T> {
+    type Output = CubicBezier<T::Output>;
+
+    fn lift(&self) -> Self::Output {
+        CubicBezier(self.0.lift(), self.1.lift(), self.2.lift(), self.3.lift())
+    }
+}
+
+impl<T: Lift> Lift for Circle<T> {
+    type Output = Circle<T::Output>;
+
+    fn lift(&self) -> Self::Output {
+        Circle {center: self.center.lift(), radius: self.radius.lift()}
+    }
+}
+
+impl<T: Lift> Lift for Translate<T> {
+    type Output = Translate<T::Output>;
+
+    fn lift(&self) -> Self::Output {Translate(self.0.lift())}
+}
+
+impl<T: Lift> Lift for Inverse<T> {
+    type Output = Inverse<T::Output>;
+
+    fn lift(&self) -> Self::Output {Inverse(self.0.lift())}
+}
+
+impl<P: Lift, Q: Lift> Lift for Concat<P, Q> {
+    type Output = Concat<P::Output, Q::Output>;
+
+    fn lift(&self) -> Self::Output {Concat(self.0.lift(), self.1.lift())}
+}
+
+impl<X1, X2, H1, H2> Lift for Square<X1, X2, H1, H2>
+    where X1: Lift, X2: Lift, H1: Lift, H2: Lift
+{
+    type Output = Square<X1::Output, X2::Output, H1::Output, H2::Output>;
+
+    fn lift(&self) -> Self::Output {Square::new(self.h1.lift(), self.h2.lift())}
+}
+
+impl<X1, X2, X3, H1, H2, H3> Lift for Cube<X1, X2, X3, H1, H2, H3>
+    where X1: Lift, X2: Lift, X3: Lift, H1: Lift, H2: Lift, H3: Lift
+{
+    type Output = Cube<X1::Output, X2::Output, X3::Output, H1::Output, H2::Output, H3::Output>;
+
+    fn lift(&self) -> Self::Output {Cube::new(self.h1.lift(), self.h2.lift(), self.h3.lift())}
+}
+
+impl<X1, X2, X3, X4, H1, H2, H3, H4> Lift for Cube4<X1, X2, X3, X4, H1, H2, H3, H4>
+    where X1: Lift, X2: Lift, X3: Lift, X4: Lift, H1: Lift, H2: Lift, H3: Lift, H4: Lift
+{
+    type Output = Cube4<X1::Output, X2::Output, X3::Output, X4::Output,
+                        H1::Output, H2::Output, H3::Output, H4::Output>;
+
+    fn lift(&self) -> Self::Output {
+        Cube4::new(self.h1.lift(), self.h2.lift(), self.h3.lift(), self.h4.lift())
+    }
+}
+
+impl<H1, H2, S1, S2> Lift for Compose<H1, H2, S1, S2>
+    where H1: Lift, H2: Lift, S1: Lift, S2: Lift
+{
+    type Output = Compose<H1::Output, H2::Output, S1::Output, S2::Output>;
+
+    fn lift(&self) -> Self::Output {Compose::new(self.h1.lift(), self.h2.lift())}
+}
+
+impl<T: Lift, S: Lift> Lift for Diagonal<T, S> {
+    type Output = Diagonal<T::Output, S::Output>;
+
+    fn lift(&self) -> Self::Output {Diagonal::new(self.shape.lift())}
+}
+
+impl<T: Lift, const AXIS: usize, const END: bool> Lift for Face<T, AXIS, END> {
+    type Output = Face<T::Output, AXIS, END>;
+
+    fn lift(&self) -> Self::Output {Face(self.0.lift())}
+}
+
+impl<T: Lift> Lift for Side<T> {
+    type Output = Side<T::Output>;
+
+    fn lift(&self) -> Self::Output {Side {shape: self.shape.lift(), axis: self.axis, end: self.end}}
+}
+
+impl<T: Lift, const AXIS: usize, F: Lift> Lift for Slice<T, AXIS, F> {
+    type Output = Slice<T::Output, AXIS, F::Output>;
+
+    fn lift(&self) -> Self::Output {Slice(self.0.lift(), self.1.lift())}
+}
+
+impl<T: Lift, S: Lift> Lift for AsVec<T, S> {
+    type Output = AsVec<T::Output, S::Output>;
+
+    fn lift(&self) -> Self::Output {AsVec::new(self.shape.lift())}
+}
+
+impl<T: Lift> Lift for Option<T> {
+    type Output = Option<T::Output>;
+
+    fn lift(&self) -> Self::Output {self.as_ref().map(|a| a.lift())}
+}
+
+impl<A: Lift, B: Lift> Lift for Zip<A, B> {
+    type Output = Zip<A::Output, B::Output>;
+
+    fn lift(&self) -> Self::Output {Zip(self.0.lift(), self.1.lift())}
+}
+
+impl<T: Lift, P: Lift> Lift for Along<T, P> {
+    type Output = Along<T::Output, P::Output>;
+
+    fn lift(&self) -> Self::Output {Along(self.0.lift(), self.1.lift())}
+}
+
+impl<T: Lift, S: Param + Lift> Lift for Restrict<T, S>
+    where <S as Lift>::Output: Param
+{
+    type Output = Restrict<T::Output, <S as Lift>::Output>;
+
+    fn lift(&self) -> Self::Output {
+        Restrict::new(self.shape().lift(), self.start().lift(), self.end().lift())
+    }
+}
+
+impl<T: Lift, const N: usize> Lift for Permute<T, N> {
+    type Output = Permute<T::Output, N>;
+
+    fn lift(&self) -> Self::Output {Permute::new(self.shape().lift(), self.axes())}
+}
+
+impl<T: Lift, const N: usize> Lift for Reflect<T, N> {
+    type Output = Reflect<T::Output, N>;
+
+    fn lift(&self) -> Self::Output {Reflect {shape: self.shape.lift(), axes: self.axes}}
+}
+
+impl<A: Lift, B: Lift> Lift for Glue<A, B> {
+    type Output = Glue<A::Output, B::Output>;
+
+    fn lift(&self) -> Self::Output {
+        Glue {first: self.first.lift(), second: self.second.lift(), axis: self.axis}
+    }
+}
+
+impl<T: Lift, S: Lower + Lift> Lift for Fill<T, S>
+    where <S as Lift>::Output: Lower
+{
+    type Output = Fill<T::Output, <S as Lift>::Output>;
+
+    fn lift(&self) -> Self::Output {Fill::new(self.sides.lift())}
+}
+
+impl<L: Lift, R: Lift, T: Lift, B: Lift> Lift for Coons<L, R, T, B> {
+    type Output = Coons<L::Output, R::Output, T::Output, B::Output>;
+
+    fn lift(&self) -> Self::Output {
+        Coons::new(self.left.lift(), self.right.lift(), self.top.lift(), self.bottom.lift())
+    }
+}
+
+impl<A: Lift, B: Lift> Lift for Loft<A, B> {
+    type Output = Loft<A::Output, B::Output>;
+
+    fn lift(&self) -> Self::Output {Loft(self.0.lift(), self.1.lift())}
+}
+
+impl<T: Lift> Lift for LoftMany<T> {
+    type Output = LoftMany<T::Output>;
+
+    fn lift(&self) -> Self::Output {
+        LoftMany::new(self.sections().iter().map(|a| a.lift()).collect(), self.blend())
+    }
+}
+
+impl<H: Lift, P: Lift> Lift for Reparam<H, P> {
+    type Output = Reparam<H::Output, P::Output>;
+
+    fn lift(&self) -> Self::Output {Reparam(self.0.lift(), self.1.lift())}
+}
+
+impl<H: Lift, P: Lift> Lift for ReparamN<H, P> {
+    type Output = ReparamN<H::Output, P::Output>;
+
+    fn lift(&self) -> Self::Output {ReparamN(self.0.lift(), self.1.lift())}
+}
+
+impl<T: Lift> Lift for ConcatMany<T> {
+    type Output = ConcatMany<T::Output>;
+
+    fn lift(&self) -> Self::Output {
+        ConcatMany::new(self.paths().iter().map(|a| a.lift()).collect())
+    }
+}
+
+impl<P: Lift> Lift for Refl<P> {
+    type Output = Refl<P::Output>;
+
+    fn lift(&self) -> Self::Output {Refl(self.0.lift())}
+}
+
+impl<P: Lift, Q: Lift, R: Lift> Lift for Assoc<P, Q, R> {
+    type Output = Assoc<P::Output, Q::Output, R::Output>;
+
+    fn lift(&self) -> Self::Output {Assoc(self.0.lift(), self.1.lift(), self.2.lift())}
+}
+
+impl<P: Lift> Lift for LeftUnit<P> {
+    type Output = LeftUnit<P::Output>;
+
+    fn lift(&self) -> Self::Output {LeftUnit(self.0.lift())}
+}
+
+impl<P: Lift> Lift for RightInverse<P> {
+    type Output = RightInverse<P::Output>;
+
+    fn lift(&self) -> Self::Output {RightInverse(self.0.lift())}
+}
+
+impl<T: Lift> Lift for Const<T> {
+    type Output = Const<T::Output>;
+
+    fn lift(&self) -> Self::Output {Const(self.0.lift())}
+}
+
+impl<T: Lift> Lift for Ops<T> {
+    type Output = Ops<T::Output>;
+
+    fn lift(&self) -> Self::Output {Ops(self.0.lift())}
+}
+
+impl<A: Lift, B: Lift> Lift for Sum<A, B> {
+    type Output = Sum<A::Output, B::Output>;
+
+    fn lift(&self) -> Self::Output {Sum(self.0.lift(), self.1.lift())}
+}
+
+impl<A: Lift, B: Lift> Lift for Difference<A, B> {
+    type Output = Difference<A::Output, B::Output>;
+
+    fn lift(&self) -> Self::Output {Difference(self.0.lift(), self.1.lift())}
+}
+
+impl<A: Lift, B: Lift> Lift for Product<A, B> {
+    type Output = Product<A::Output, B::Output>;
+
+    fn lift(&self) -> Self::Output {Product(self.0.lift(), self.1.lift())}
+}
+
+/// The table of arc lengths is linear between entries,
+/// so this is differentiated with `FiniteDiff`.
+///
+/// Second derivatives, e.g. for `curvature`, are approximate where the steps cross an entry.
+impl<H: Clone> Lift for ArcLength<H> {
+    type Output = FiniteDiff<Self>;
+
+    fn lift(&self) -> Self::Output {FiniteDiff(self.clone(), FINITE_DIFF_STEP)}
+}
+
+/// See `FiniteDiff`.
+impl<H, F, Y1, Y2, S> Lift for SMap<H, F, Y1, Y2, S>
+    where Self: Clone, S: Param, F: Fn(Y1, S::Float) -> Y2
+{
+    type Output = FiniteDiff<Self>;
+
+    fn lift(&self) -> Self::Output {FiniteDiff(self.clone(), FINITE_DIFF_STEP)}
+}
+
+/// See `FiniteDiff`.
+impl<H, F, X1, X2> Lift for Contramap<H, F, X1, X2>
+    where Self: Clone, F: Fn(X2) -> X1
+{
+    type Output = FiniteDiff<Self>;
+
+    fn lift(&self) -> Self::Output {FiniteDiff(self.clone(), FINITE_DIFF_STEP)}
+}
+
+/// See `FiniteDiff`.
+impl<H, F, Y1, Y2> Lift for Map<H, F, Y1, Y2>
+    where Self: Clone, F: Fn(Y1) -> Y2
+{
+    type Output = FiniteDiff<Self>;
+
+    fn lift(&self) -> Self::Output {FiniteDiff(self.clone(), FINITE_DIFF_STEP)}
+}
+
+/// Nests central differences for higher derivatives, see `Partial`.
+///
+/// The step grows with each nesting, e.g. from `1e-6` to about `3e-5`,
+/// since rounding errors are divided by every step.
+impl<T: Clone> Lift for FiniteDiff<T> {
+    type Output = FiniteDiff<Self>;
+
+    fn lift(&self) -> Self::Output {FiniteDiff(self.clone(), self.1.powf(0.75))}
+}
+
+/// The default step used by `FiniteDiff` when lifting closures, e.g. in `Map`.
+pub const FINITE_DIFF_STEP: f64 = 1e-6;
+
+/// Lifts a homotopy to dual numbers by central differences with some step.
+///
+/// This is a fallback for homotopies that can not be lifted exactly.
+/// Closures can not be lifted, so `Map`, `SMap` and `Contramap` are lifted to this.
+/// The derivative is taken along the derivatives of both the input and the parameter,
+/// so it works inside `Compose`.
+/// The homotopy is evaluated one step outside the parameter cube at the sides.
+#[derive(Copy, Clone)]
+pub struct FiniteDiff<T>(pub T, pub f64);
+
+impl<X, S, T> Homotopy<X, S> for FiniteDiff<T>
+    where X: Tangent,
+          S: Tangent,
+          T: Homotopy<X::Base, S::Base>,
+          T::Y: VectorSpace + Lift,
+          <T::Y as Lift>::Output: Tangent<Base = T::Y>,
+{
+    type Y = <T::Y as Lift>::Output;
+
+    fn f(&self, x: X) -> Self::Y {
+        let value = self.0.f(x.value());
+        let tangent = self.diff(self.0.f(x.step(self.1)), self.0.f(x.step(-self.1)));
+        Tangent::from_parts(value, tangent)
+    }
+    fn g(&self, x: X) -> Self::Y {
+        let value = self.0.g(x.value());
+        let tangent = self.diff(self.0.g(x.step(self.1)), self.0.g(x.step(-self.1)));
+        Tangent::from_parts(value, tangent)
+    }
+    fn h(&self, x: X, s: S) -> Self::Y {
+        let value = self.0.h(x.value(), s.value());
+        let tangent = self.diff(
+            self.0.h(x.step(self.1), s.step(self.1)),
+            self.0.h(x.step(-self.1), s.step(-self.1))
+        );
+        Tangent::from_parts(value, tangent)
+    }
+}
+
+impl<T> FiniteDiff<T> {
+    /// Computes the central difference of two outputs one step before and after.
+    fn diff<Y: VectorSpace>(&self, after: Y, before: Y) -> Y {
+        after.sub_vector(&before).scale(Y::Scalar::from_f64(0.5 / self.1))
+    }
+}
+
+/// Derivatives of a homotopy with respect to its parameters.
+///
+/// This is implemented for every homotopy that can be lifted to dual numbers, see `Lift`,
+/// using forward-mode automatic differentiation.
+/// The derivatives are exact through shapes, e.g. `Lerp`, `Circle` and `Square`,
+/// and through combinators, e.g. `Compose`, the sides, `Loft` and `Coons`.
+/// Closures, e.g. in `Map`, and the table of `ArcLength` are differentiated with `FiniteDiff`.
+pub trait Differentiable<X, S: Param>: Homotopy<X, S> {
+    /// Gets the derivative with respect to the parameter of some axis.
+    fn partial(&self, x: X, s: S, axis: usize) -> Self::Y;
+
+    /// Gets the derivative with respect to the parameter of a 1D homotopy.
+    fn derivative(&self, x: X, s: S) -> Self::Y {
+        assert_eq!(S::DIM, 1);
+        self.partial(x, s, 0)
+    }
+
+    /// Gets the derivatives with respect to the parameters of all axes.
+    fn jacobian(&self, x: X, s: S) -> Vec<Self::Y>
+        where X: Clone
+    {
+        (0..S::DIM).map(|axis| self.partial(x.clone(), s, axis)).collect()
+    }
+}
+
+impl<X, S, H> Differentiable<X, S> for H
+    where H: Homotopy<X, S> + Lift,
+          X: Lift,
+          S: Param + Lift,
+          S::Output: Tangent<Base = S>,
+          <H as Lift>::Output: Homotopy<X::Output, S::Output>,
+          <<H as Lift>::Output as Homotopy<X::Output, S::Output>>::Y: Tangent<Base = H::Y>,
+{
+    fn partial(&self, x: X, s: S, axis: usize) -> Self::Y {
+        assert!(axis < S::DIM);
+        let seed = S::from_fn(|i| if i == axis {S::Float::one()} else {S::Float::zero()});
+        self.lift().h(x.lift(), Tangent::from_parts(s, seed)).tangent()
+    }
+}
+
+/// The partial derivative of a homotopy with respect to the parameter of some axis.
+///
+/// This is itself a homotopy, which can be differentiated again for higher derivatives,
+/// e.g. `Partial<Partial<T, 0>, 1>` is a mixed second derivative.
+/// The endpoints are the derivatives at the corners of the parameter cube.
+#[derive(Copy, Clone)]
+pub struct Partial<T, const AXIS: usize>(pub T);
+
+/// The derivative of a 1D homotopy.
+pub type Derivative<T> = Partial<T, 0>;
+
+impl<X, S, T, const AXIS: usize> Homotopy<X, S> for Partial<T, AXIS>
+    where S: Param, T: Differentiable<X, S>
+{
+    type Y = T::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0.partial(x, S::splat(S::Float::zero()), AXIS)}
+    fn g(&self, x: X) -> Self::Y {self.0.partial(x, S::splat(S::Float::one()), AXIS)}
+    fn h(&self, x: X, s: S) -> Self::Y {self.0.partial(x, s, AXIS)}
+}
+
+impl<T: Lift, const AXIS: usize> Lift for Partial<T, AXIS> {
+    type Output = Partial<T::Output, AXIS>;
+
+    fn lift(&self) -> Self::Output {Partial(self.0.lift())}
+}
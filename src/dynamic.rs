@@ -0,0 +1,127 @@
+use super::*;
+
+use std::fmt;
+use std::sync::Arc;
+
+/// An object-safe homotopy, with the dimension known at runtime.
+///
+/// This is used to store shapes of different types in one collection,
+/// e.g. `Vec<Box<dyn DynHomotopy<X, Y>>>`, or to choose shapes at runtime.
+/// Any homotopy can be converted with `Homotopy::into_box` or `Homotopy::into_arc`,
+/// and converted back with `Static`.
+pub trait DynHomotopy<X, Y> {
+    /// The number of dimensions.
+    fn dim(&self) -> usize;
+    /// The function being mapped from.
+    fn f(&self, x: X) -> Y;
+    /// The function being mapped to.
+    fn g(&self, x: X) -> Y;
+    /// A continuous map such that `h(x, &[0.0, ...])` is `f(x)` and `h(x, &[1.0, ...])` is `g(x)`.
+    ///
+    /// Panics if the number of parameters is not the number of dimensions.
+    fn h(&self, x: X, s: &[f64]) -> Y;
+}
+
+impl<X, Y, T: ?Sized + DynHomotopy<X, Y>> DynHomotopy<X, Y> for &T {
+    fn dim(&self) -> usize {T::dim(self)}
+    fn f(&self, x: X) -> Y {T::f(self, x)}
+    fn g(&self, x: X) -> Y {T::g(self, x)}
+    fn h(&self, x: X, s: &[f64]) -> Y {T::h(self, x, s)}
+}
+
+impl<X, Y, T: ?Sized + DynHomotopy<X, Y>> DynHomotopy<X, Y> for Box<T> {
+    fn dim(&self) -> usize {T::dim(self)}
+    fn f(&self, x: X) -> Y {T::f(self, x)}
+    fn g(&self, x: X) -> Y {T::g(self, x)}
+    fn h(&self, x: X, s: &[f64]) -> Y {T::h(self, x, s)}
+}
+
+impl<X, Y, T: ?Sized + DynHomotopy<X, Y>> DynHomotopy<X, Y> for Arc<T> {
+    fn dim(&self) -> usize {T::dim(self)}
+    fn f(&self, x: X) -> Y {T::f(self, x)}
+    fn g(&self, x: X) -> Y {T::g(self, x)}
+    fn h(&self, x: X, s: &[f64]) -> Y {T::h(self, x, s)}
+}
+
+/// Converts a homotopy into a `DynHomotopy`.
+///
+/// `S` is the parameter type of the homotopy.
+#[derive(Copy, Clone)]
+pub struct Dyn<H, S> {
+    hom: H,
+    _s: PhantomData<S>,
+}
+
+impl<H, S> Dyn<H, S> {
+    /// Creates a new dynamic homotopy.
+    pub fn new(h: H) -> Self {
+        Dyn {hom: h, _s: PhantomData}
+    }
+}
+
+impl<X, H, S> DynHomotopy<X, H::Y> for Dyn<H, S>
+    where S: Param, H: Homotopy<X, S>
+{
+    fn dim(&self) -> usize {S::DIM}
+    fn f(&self, x: X) -> H::Y {self.hom.f(x)}
+    fn g(&self, x: X) -> H::Y {self.hom.g(x)}
+    fn h(&self, x: X, s: &[f64]) -> H::Y {
+        assert_eq!(s.len(), S::DIM);
+        self.hom.h(x, S::from_fn(|i| S::Float::from_f64(s[i])))
+    }
+}
+
+/// An error when a dynamic homotopy does not have the expected dimension.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DimError {
+    /// The expected number of dimensions.
+    pub expected: usize,
+    /// The number of dimensions found.
+    pub found: usize,
+}
+
+impl fmt::Display for DimError {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        write!(w, "expected {} dimensions, found {}", self.expected, self.found)
+    }
+}
+
+impl std::error::Error for DimError {}
+
+/// Converts a `DynHomotopy` back into a homotopy with a static parameter type.
+///
+/// This makes the static combinators available again, e.g. `left`, `diagonal` and `map`.
+#[derive(Copy, Clone)]
+pub struct Static<T, Y, S> {
+    inner: T,
+    _y: PhantomData<Y>,
+    _s: PhantomData<S>,
+}
+
+impl<T, Y, S: Param> Static<T, Y, S> {
+    /// Creates a new static homotopy, checking that the dimension is `S::DIM`.
+    pub fn try_new<X>(inner: T) -> Result<Self, DimError>
+        where T: DynHomotopy<X, Y>
+    {
+        if inner.dim() != S::DIM {
+            return Err(DimError {expected: S::DIM, found: inner.dim()})
+        }
+        Ok(Static {inner, _y: PhantomData, _s: PhantomData})
+    }
+
+    /// Gets the dynamic homotopy.
+    pub fn into_inner(self) -> T {self.inner}
+}
+
+impl<X, Y, T, S> Homotopy<X, S> for Static<T, Y, S>
+    where S: Param, T: DynHomotopy<X, Y>
+{
+    type Y = Y;
+
+    fn f(&self, x: X) -> Y {self.inner.f(x)}
+    fn g(&self, x: X) -> Y {self.inner.g(x)}
+    fn h(&self, x: X, s: S) -> Y {
+        let s: Vec<f64> = (0..S::DIM).map(|i| s.get(i).to_f64()).collect();
+        self.inner.h(x, &s)
+    }
+}
@@ -0,0 +1,199 @@
+//! Easing curves, used as reparametrizations with `Reparam`.
+
+use super::*;
+
+use std::f64::consts::LN_2;
+
+/// Which end of an easing curve is slow.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Ease {
+    /// Starts slow.
+    In,
+    /// Ends slow.
+    Out,
+    /// Starts and ends slow.
+    InOut,
+}
+
+impl Ease {
+    /// Applies an ease-in curve, deriving the ease-out and ease-in-out curves from it.
+    pub fn apply<F: Float, E: Fn(F) -> F>(self, s: F, ease_in: E) -> F {
+        let (one, half, two) = (F::one(), F::from_f64(0.5), F::from_f64(2.0));
+        match self {
+            Ease::In => ease_in(s),
+            Ease::Out => one - ease_in(one - s),
+            Ease::InOut => {
+                if s < half {half * ease_in(two * s)}
+                else {one - half * ease_in(two - two * s)}
+            }
+        }
+    }
+}
+
+/// An easing curve from `[0.0, 1.0]` to `[0.0, 1.0]` that keeps `0.0` and `1.0` fixed.
+pub trait Easing {
+    /// Whether the curve goes outside `[0.0, 1.0]`.
+    ///
+    /// Shapes that are reparametrized by such a curve go beyond their endpoints.
+    const OVERSHOOTS: bool;
+
+    /// Evaluates the curve.
+    ///
+    /// This is computed in the scalar type, so dual numbers carry the derivative, see `Dual`.
+    fn ease<F: Float>(&self, s: F) -> F;
+}
+
+macro_rules! easing {
+    ($($name:ident),*) => {$(
+        impl<F: Float> Homotopy<(), F> for $name {
+            type Y = F;
+
+            fn f(&self, _: ()) -> F {F::zero()}
+            fn g(&self, _: ()) -> F {F::one()}
+            fn h(&self, _: (), s: F) -> F {
+                // Handle special cases to get exact values.
+                if s == F::zero() || s == F::one() {return s};
+                self.ease(s)
+            }
+        }
+
+        impl Lift for $name {
+            type Output = $name;
+
+            fn lift(&self) -> $name {*self}
+        }
+    )*}
+}
+
+easing!(Quad, Cubic, Quartic, Sine, Expo, Back, Elastic, Bounce, Steps);
+
+/// Quadratic easing.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Quad(pub Ease);
+
+impl Easing for Quad {
+    const OVERSHOOTS: bool = false;
+
+    fn ease<F: Float>(&self, s: F) -> F {self.0.apply(s, |s| s * s)}
+}
+
+/// Cubic easing.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cubic(pub Ease);
+
+impl Easing for Cubic {
+    const OVERSHOOTS: bool = false;
+
+    fn ease<F: Float>(&self, s: F) -> F {self.0.apply(s, |s| s * s * s)}
+}
+
+/// Quartic easing.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Quartic(pub Ease);
+
+impl Easing for Quartic {
+    const OVERSHOOTS: bool = false;
+
+    fn ease<F: Float>(&self, s: F) -> F {self.0.apply(s, |s| s * s * s * s)}
+}
+
+/// Sine easing, following a quarter of a cosine wave.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Sine(pub Ease);
+
+impl Easing for Sine {
+    const OVERSHOOTS: bool = false;
+
+    fn ease<F: Float>(&self, s: F) -> F {
+        self.0.apply(s, |s| F::one() - (F::from_f64(0.5) * F::pi() * s).cos())
+    }
+}
+
+/// Exponential easing.
+///
+/// This is normalized such that it is continuous at `0.0`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Expo(pub Ease);
+
+fn expo_in<F: Float>(s: F) -> F {
+    let c = F::from_f64;
+    // `2^(10 s)`, written with `exp` since `Float` has no `powf`.
+    ((c(10.0 * LN_2) * s).exp() - F::one()) / c(1023.0)
+}
+
+impl Easing for Expo {
+    const OVERSHOOTS: bool = false;
+
+    fn ease<F: Float>(&self, s: F) -> F {self.0.apply(s, expo_in)}
+}
+
+/// Back easing, pulling back before moving forward.
+///
+/// This overshoots `[0.0, 1.0]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Back(pub Ease);
+
+impl Easing for Back {
+    const OVERSHOOTS: bool = true;
+
+    fn ease<F: Float>(&self, s: F) -> F {
+        let c = F::from_f64(1.70158);
+        self.0.apply(s, |s| (c + F::one()) * s * s * s - c * s * s)
+    }
+}
+
+/// Elastic easing, oscillating with growing amplitude.
+///
+/// This overshoots `[0.0, 1.0]`.
+/// The amplitude is normalized such that it is continuous at `0.0`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Elastic(pub Ease);
+
+impl Easing for Elastic {
+    const OVERSHOOTS: bool = true;
+
+    fn ease<F: Float>(&self, s: F) -> F {
+        let c = F::from_f64;
+        self.0.apply(s, |s| {
+            -expo_in(s) * ((c(10.0) * s - c(10.75)) * c(2.0) * F::pi() / c(3.0)).sin()
+        })
+    }
+}
+
+/// Bounce easing, bouncing off the start.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Bounce(pub Ease);
+
+fn bounce_out<F: Float>(s: F) -> F {
+    let c = F::from_f64;
+    let (n, d) = (7.5625, 2.75);
+    if s < c(1.0 / d) {c(n) * s * s}
+    else if s < c(2.0 / d) {c(n) * (s - c(1.5 / d)).powi(2) + c(0.75)}
+    else if s < c(2.5 / d) {c(n) * (s - c(2.25 / d)).powi(2) + c(0.9375)}
+    else {c(n) * (s - c(2.625 / d)).powi(2) + c(0.984375)}
+}
+
+impl Easing for Bounce {
+    const OVERSHOOTS: bool = false;
+
+    fn ease<F: Float>(&self, s: F) -> F {self.0.apply(s, |s| F::one() - bounce_out(F::one() - s))}
+}
+
+/// Steps easing, jumping between a number of equal steps.
+///
+/// This is not continuous, so reparametrizing by it does not give a homotopy,
+/// but `f` and `g` are kept.
+///
+/// Panics if the number of steps is zero.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Steps(pub usize);
+
+impl Easing for Steps {
+    const OVERSHOOTS: bool = false;
+
+    fn ease<F: Float>(&self, s: F) -> F {
+        assert!(self.0 > 0);
+        let n = F::from_f64(self.0 as f64);
+        (s * n).floor() / n
+    }
+}
@@ -0,0 +1,224 @@
+use super::*;
+
+use std::fmt;
+
+/// The number of samples per axis used to check that sides agree.
+pub(crate) const SIDE_SAMPLES: usize = 8;
+
+/// An error when filling an N-dimensional homotopy from its sides.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FillError<Y> {
+    /// Expected two sides per axis.
+    Sides {
+        /// The expected number of sides.
+        expected: usize,
+        /// The number of sides found.
+        found: usize,
+    },
+    /// More than one side is missing.
+    Missing {
+        /// The number of missing sides.
+        count: usize,
+    },
+    /// Two sides do not agree where they meet.
+    Mismatch {
+        /// The axis and end of the first side.
+        first: (usize, bool),
+        /// The axis and end of the second side.
+        second: (usize, bool),
+        /// Where the sides do not agree.
+        mismatch: Mismatch<Y>,
+    },
+}
+
+impl<Y: fmt::Debug> fmt::Display for FillError<Y> {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FillError::Sides {expected, found} =>
+                write!(w, "expected {} sides, found {}", expected, found),
+            FillError::Missing {count} =>
+                write!(w, "at most one side can be missing, found {}", count),
+            FillError::Mismatch {first, second, ref mismatch} =>
+                write!(w, "sides {:?} and {:?} do not agree, {}", first, second, mismatch),
+        }
+    }
+}
+
+impl<Y: fmt::Debug> std::error::Error for FillError<Y> {}
+
+/// The parameter at the start (`0.0`) or end (`1.0`) of an axis.
+fn end<T: Float>(end: bool) -> T {if end {T::one()} else {T::zero()}}
+
+/// Fills an N-dimensional homotopy from its N-1 dimensional sides.
+///
+/// This is a Kan-style filler, generalizing `Coons` to any dimension.
+/// The sides are ordered by axis, with the start before the end, like `Homotopy::sides`.
+/// E.g. for a 3D homotopy, the sides are left, right, top, bottom, front and back.
+///
+/// One side can be missing, which is the open box case.
+/// The missing side is computed from the sides it shares edges with.
+///
+/// The inside is blended from the sides such that the sides are reproduced exactly.
+///
+/// All sides have the same type `T`.
+/// The faces of one shape, e.g. `a.left()` and `a.right()`, have different types,
+/// so they are converted to `Side` with `into()`, or taken from `Homotopy::sides`.
+/// Sides of different shapes are boxed with `Homotopy::into_box`
+/// and converted back with `Static`.
+#[derive(Clone)]
+pub struct Fill<T, S> {
+    pub(crate) sides: Vec<Option<T>>,
+    _s: PhantomData<S>,
+}
+
+impl<T, S: Lower> Fill<T, S> {
+    /// Creates a new filler from sides, without checking that they agree.
+    ///
+    /// Panics if there are not two sides per axis or more than one side is missing.
+    pub fn new(sides: Vec<Option<T>>) -> Self {
+        assert_eq!(sides.len(), 2 * S::DIM);
+        assert!(sides.iter().filter(|side| side.is_none()).count() <= 1);
+        Fill {sides, _s: PhantomData}
+    }
+
+    /// Creates a new filler from sides,
+    /// checking that they agree within tolerance `eps` for some input `x`.
+    pub fn try_new<X>(sides: Vec<Option<T>>, x: X, eps: f64) -> Result<Self, FillError<T::Y>>
+        where T: Homotopy<X, S::Output>, T::Y: ApproxEq, X: Clone
+    {
+        if sides.len() != 2 * S::DIM {
+            return Err(FillError::Sides {expected: 2 * S::DIM, found: sides.len()})
+        }
+        let count = sides.iter().filter(|side| side.is_none()).count();
+        if count > 1 {return Err(FillError::Missing {count})};
+        let fill = Fill {sides, _s: PhantomData};
+        fill.check_sides(x, eps)?;
+        Ok(fill)
+    }
+
+    /// Gets the side at the start or end of some axis, if it is not missing.
+    pub fn side(&self, axis: usize, end: bool) -> Option<&T> {
+        self.sides[2 * axis + end as usize].as_ref()
+    }
+
+    /// Checks that the sides agree where they meet,
+    /// within tolerance `eps` for some input `x`.
+    ///
+    /// Each pair of sides is compared on a grid where they meet.
+    /// Returns the first pair of sides that do not agree.
+    pub fn check_sides<X>(&self, x: X, eps: f64) -> Result<(), FillError<T::Y>>
+        where T: Homotopy<X, S::Output>, T::Y: ApproxEq, X: Clone
+    {
+        let n = S::DIM;
+        let free = (SIDE_SAMPLES + 1).pow(n as u32 - 2);
+        for i in 0..2 * n {
+            for j in i + 1..2 * n {
+                let (a, b) = (i / 2, j / 2);
+                if a == b {continue};
+                let (first, second) = match (&self.sides[i], &self.sides[j]) {
+                    (Some(first), Some(second)) => (first, second),
+                    _ => continue,
+                };
+                let (end_a, end_b) = (i % 2 == 1, j % 2 == 1);
+                for ind in 0..free {
+                    // Decode grid coordinates for the axes that are not fixed.
+                    let mut k = ind;
+                    let s = S::from_fn(|axis| {
+                        if axis == a {return end(end_a)};
+                        if axis == b {return end(end_b)};
+                        let digit = k % (SIDE_SAMPLES + 1);
+                        k /= SIDE_SAMPLES + 1;
+                        S::Float::from_f64(digit as f64 / SIDE_SAMPLES as f64)
+                    });
+                    expect_approx(
+                        (0..n).map(|axis| s.get(axis).to_f64()).collect(),
+                        first.h(x.clone(), s.remove(a)),
+                        second.h(x.clone(), s.remove(b)),
+                        eps
+                    ).map_err(|mismatch| FillError::Mismatch {
+                        first: (a, end_a),
+                        second: (b, end_b),
+                        mismatch,
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluates the sides where the axes in `mask` are fixed at `ends`.
+    fn boundary<X>(&self, x: X, s: S, mask: usize, ends: usize) -> T::Y
+        where T: Homotopy<X, S::Output>, T::Y: VectorSpace<Scalar = S::Float>, X: Clone
+    {
+        let s = S::from_fn(|i| {
+            if mask & (1 << i) == 0 {s.get(i)}
+            else {end(ends & (1 << i) != 0)}
+        });
+        for axis in (0..S::DIM).filter(|&i| mask & (1 << i) != 0) {
+            if let Some(side) = self.side(axis, ends & (1 << axis) != 0) {
+                return side.h(x, s.remove(axis))
+            }
+        }
+        // Only the missing side is fixed, so compute it from the other sides.
+        self.blend(x, s, mask)
+    }
+
+    /// Blends the sides of all axes, except those in `skip`.
+    fn blend<X>(&self, x: X, s: S, skip: usize) -> T::Y
+        where T: Homotopy<X, S::Output>, T::Y: VectorSpace<Scalar = S::Float>, X: Clone
+    {
+        let mut sum: Option<T::Y> = None;
+        for mask in 1..1usize << S::DIM {
+            if mask & skip != 0 {continue};
+            let sign = if mask.count_ones() % 2 == 1 {S::Float::one()} else {-S::Float::one()};
+            // Visit every combination of ends for the fixed axes.
+            let mut ends = mask;
+            loop {
+                let weight = (0..S::DIM).filter(|&i| mask & (1 << i) != 0)
+                    .map(|i| if ends & (1 << i) == 0 {S::Float::one() - s.get(i)} else {s.get(i)})
+                    .fold(S::Float::one(), |a, b| a * b);
+                let term = self.boundary(x.clone(), s, mask, ends).scale(sign * weight);
+                sum = Some(match sum {
+                    None => term,
+                    Some(sum) => sum.add_vector(&term),
+                });
+                if ends == 0 {break};
+                ends = (ends - 1) & mask;
+            }
+        }
+        sum.expect("expected at least one axis")
+    }
+}
+
+impl<X, T, S> Homotopy<X, S> for Fill<T, S>
+    where S: Lower,
+          T: Homotopy<X, S::Output>,
+          T::Y: VectorSpace<Scalar = S::Float>,
+          X: Clone,
+{
+    type Y = T::Y;
+
+    fn f(&self, x: X) -> Self::Y {
+        let axis = (0..S::DIM).find(|&i| self.side(i, false).is_some()).unwrap();
+        self.side(axis, false).unwrap().f(x)
+    }
+    fn g(&self, x: X) -> Self::Y {
+        let axis = (0..S::DIM).find(|&i| self.side(i, true).is_some()).unwrap();
+        self.side(axis, true).unwrap().g(x)
+    }
+    fn h(&self, x: X, s: S) -> Self::Y {
+        // Use the sides directly to reproduce them exactly.
+        let mut missing = None;
+        for axis in 0..S::DIM {
+            let v = s.get(axis);
+            if v != S::Float::zero() && v != S::Float::one() {continue};
+            if let Some(side) = self.side(axis, v == S::Float::one()) {
+                return side.h(x, s.remove(axis))
+            }
+            missing = Some(axis);
+        }
+        if let Some(axis) = missing {return self.blend(x, s, 1 << axis)};
+
+        self.blend(x, s, 0)
+    }
+}
@@ -0,0 +1,62 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A floating-point scalar, e.g. `f32` or `f64`.
+///
+/// This is the scalar type of parameters, see `Param`,
+/// and of vector spaces, see `VectorSpace`.
+/// Other scalars, e.g. with higher precision, can be used by implementing this trait.
+pub trait Float:
+    Copy + PartialOrd + fmt::Debug +
+    Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> +
+    Neg<Output = Self>
+{
+    /// Converts from `f64`, rounding if necessary.
+    fn from_f64(v: f64) -> Self;
+    /// Converts to `f64`, rounding if necessary.
+    fn to_f64(self) -> f64;
+
+    /// Returns `0.0`.
+    fn zero() -> Self {Self::from_f64(0.0)}
+    /// Returns `1.0`.
+    fn one() -> Self {Self::from_f64(1.0)}
+    /// Returns `π`.
+    ///
+    /// The default rounds `π` through `f64`, so scalars with higher precision should override it.
+    fn pi() -> Self {Self::from_f64(std::f64::consts::PI)}
+
+    /// Returns the largest integer less than or equal to the value.
+    fn floor(self) -> Self;
+    /// Returns the absolute value.
+    fn abs(self) -> Self;
+    /// Returns the square root.
+    fn sqrt(self) -> Self;
+    /// Returns the sine, in radians.
+    fn sin(self) -> Self;
+    /// Returns the cosine, in radians.
+    fn cos(self) -> Self;
+    /// Returns `e` to the power of the value.
+    fn exp(self) -> Self;
+    /// Raises to an integer power.
+    fn powi(self, n: i32) -> Self;
+}
+
+macro_rules! float {
+    ($($ty:ident),*) => {$(
+        impl Float for $ty {
+            fn from_f64(v: f64) -> $ty {v as $ty}
+            fn to_f64(self) -> f64 {self as f64}
+            fn pi() -> $ty {std::$ty::consts::PI}
+
+            fn floor(self) -> $ty {$ty::floor(self)}
+            fn abs(self) -> $ty {$ty::abs(self)}
+            fn sqrt(self) -> $ty {$ty::sqrt(self)}
+            fn sin(self) -> $ty {$ty::sin(self)}
+            fn cos(self) -> $ty {$ty::cos(self)}
+            fn exp(self) -> $ty {$ty::exp(self)}
+            fn powi(self, n: i32) -> $ty {$ty::powi(self, n)}
+        }
+    )*}
+}
+
+float!(f32, f64);
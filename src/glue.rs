@@ -0,0 +1,97 @@
+use super::*;
+
+use fill::SIDE_SAMPLES;
+
+/// Glues two N-dimensional homotopies along a shared face,
+/// running the first on `[0.0, 0.5]` and the second on `[0.5, 1.0]` of some axis.
+///
+/// The end face of the first homotopy should be the start face of the second,
+/// e.g. for axis `0` the right side of the first should be the left side of the second,
+/// see `Glue::check_face`.
+/// This generalizes `Concat` to any dimension.
+#[derive(Copy, Clone)]
+pub struct Glue<A, B> {
+    pub(crate) first: A,
+    pub(crate) second: B,
+    pub(crate) axis: usize,
+}
+
+impl<A, B> Glue<A, B> {
+    /// Glues two homotopies along some axis, without checking the shared face.
+    ///
+    /// Panics if the axis is not less than the number of dimensions.
+    pub fn new<X, S>(first: A, second: B, axis: usize) -> Self
+        where S: Param, A: Homotopy<X, S>
+    {
+        assert!(axis < S::DIM, "expected an axis less than {}, found {}", S::DIM, axis);
+        Glue {first, second, axis}
+    }
+
+    /// Glues two homotopies along some axis,
+    /// checking that the shared face agrees within tolerance `eps` for some input `x`.
+    ///
+    /// Panics if the axis is not less than the number of dimensions.
+    pub fn try_new<X, Y, S>(first: A, second: B, axis: usize, x: X, eps: f64)
+    -> Result<Self, Mismatch<Y>>
+        where S: Param,
+              A: Homotopy<X, S, Y = Y>,
+              B: Homotopy<X, S, Y = Y>,
+              Y: ApproxEq,
+              X: Clone,
+    {
+        let glue = Glue::new::<X, S>(first, second, axis);
+        glue.check_face(x, eps)?;
+        Ok(glue)
+    }
+
+    /// Gets the axis the homotopies are glued along.
+    pub fn axis(&self) -> usize {self.axis}
+
+    /// Checks that the end face of the first homotopy is the start face of the second,
+    /// within tolerance `eps` for some input `x`.
+    ///
+    /// The faces are compared on a grid.
+    /// Returns the first point where they do not agree.
+    pub fn check_face<X, Y, S>(&self, x: X, eps: f64) -> Result<(), Mismatch<Y>>
+        where S: Param,
+              A: Homotopy<X, S, Y = Y>,
+              B: Homotopy<X, S, Y = Y>,
+              Y: ApproxEq,
+              X: Clone,
+    {
+        for ind in 0..(SIDE_SAMPLES + 1).pow(S::DIM as u32 - 1) {
+            // Decode grid coordinates for the axes that are not glued.
+            let mut k = ind;
+            let s = S::from_fn(|i| {
+                if i == self.axis {return S::Float::from_f64(0.5)};
+                let digit = k % (SIDE_SAMPLES + 1);
+                k /= SIDE_SAMPLES + 1;
+                S::Float::from_f64(digit as f64 / SIDE_SAMPLES as f64)
+            });
+            let at = |v| S::from_fn(|i| if i == self.axis {v} else {s.get(i)});
+            expect_approx(
+                (0..S::DIM).map(|i| s.get(i).to_f64()).collect(),
+                self.first.h(x.clone(), at(S::Float::one())),
+                self.second.h(x.clone(), at(S::Float::zero())),
+                eps
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<X, A, B, S> Homotopy<X, S> for Glue<A, B>
+    where S: Param, A: Homotopy<X, S>, B: Homotopy<X, S, Y = A::Y>
+{
+    type Y = A::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.first.f(x)}
+    fn g(&self, x: X) -> Self::Y {self.second.g(x)}
+    fn h(&self, x: X, s: S) -> Self::Y {
+        let v = s.get(self.axis);
+        let at = |v| S::from_fn(|i| if i == self.axis {v} else {s.get(i)});
+        let two = S::Float::from_f64(2.0);
+        if v < S::Float::from_f64(0.5) {self.first.h(x, at(two * v))}
+        else {self.second.h(x, at(two * v - S::Float::one()))}
+    }
+}
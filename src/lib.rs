@@ -2,14 +2,52 @@
 
 #![deny(missing_docs)]
 
-use std::ops::{Add, Sub, Mul};
 use std::marker::PhantomData;
+use std::sync::Arc;
 
+pub use float::*;
 pub use sides::*;
 pub use compose::*;
-
+pub use param::*;
+pub use check::*;
+pub use continuity::*;
+pub use vector::*;
+pub use coons::*;
+pub use fill::*;
+pub use path::*;
+pub use reparam::*;
+pub use glue::*;
+pub use ops::*;
+pub use loft::*;
+pub use shape::*;
+pub use dynamic::*;
+pub use dual::*;
+pub use arc::*;
+pub use curve::*;
+pub use surface::*;
+
+mod float;
 mod sides;
 mod compose;
+mod param;
+mod check;
+mod continuity;
+mod vector;
+mod coons;
+mod fill;
+mod path;
+mod reparam;
+mod glue;
+mod ops;
+mod loft;
+mod shape;
+mod dynamic;
+mod dual;
+mod arc;
+mod curve;
+mod surface;
+
+pub mod easing;
 
 /// A continuous map between two functions.
 pub trait Homotopy<X, Scalar=f64>: Sized {
@@ -29,212 +67,329 @@ pub trait Homotopy<X, Scalar=f64>: Sized {
     fn hu(&self, s: Scalar) -> Self::Y where X: Default {self.h(Default::default(), s)}
 
     /// Gets the inverse.
-    fn inverse<'a>(&'a self) -> Inverse<&'a Self> {Inverse(self)}
+    fn inverse(&self) -> Inverse<&Self> {Inverse(self)}
+
+    /// Concatenates with another path, running this on `[0.0, 0.5]`
+    /// and the other on `[0.5, 1.0]`.
+    fn concat<'a, Q>(&'a self, q: Q) -> Concat<&'a Self, Q>
+        where Concat<&'a Self, Q>: Homotopy<X, Scalar>
+    {
+        Concat(self, q)
+    }
+
+    /// Concatenates with another path, running this on `[0.0, 0.5]`
+    /// and the other on `[0.5, 1.0]`.
+    fn into_concat<Q>(self, q: Q) -> Concat<Self, Q>
+        where Concat<Self, Q>: Homotopy<X, Scalar>
+    {
+        Concat(self, q)
+    }
+
+    /// Reparametrizes with a monotone map from `[0.0, 1.0]` to `[0.0, 1.0]`.
+    fn reparam<'a, P>(&'a self, p: P) -> Reparam<&'a Self, P>
+        where Reparam<&'a Self, P>: Homotopy<X, Scalar>
+    {
+        Reparam(self, p)
+    }
+
+    /// Reparametrizes with a monotone map from `[0.0, 1.0]` to `[0.0, 1.0]`.
+    fn into_reparam<P>(self, p: P) -> Reparam<Self, P>
+        where Reparam<Self, P>: Homotopy<X, Scalar>
+    {
+        Reparam(self, p)
+    }
 
     /// Gets the diagonal.
     fn diagonal<'a>(&'a self) -> Diagonal<&'a Self, Scalar>
-        where Diagonal<&'a Self, Scalar>: Homotopy<X>
+        where Scalar: Param, Diagonal<&'a Self, Scalar>: Homotopy<X, Scalar::Float>
     {
         Diagonal::new(self)
     }
 
     /// Gets the diagonal.
     fn into_diagonal(self) -> Diagonal<Self, Scalar>
-        where Diagonal<Self, Scalar>: Homotopy<X>
+        where Scalar: Param, Diagonal<Self, Scalar>: Homotopy<X, Scalar::Float>
     {
         Diagonal::new(self)
     }
 
+    /// Walks along a path through the parameter cube.
+    fn along<'a, P>(&'a self, p: P) -> Along<&'a Self, P>
+        where Scalar: Param, Along<&'a Self, P>: Homotopy<X, Scalar::Float>
+    {
+        Along(self, p)
+    }
+
+    /// Walks along a path through the parameter cube.
+    fn into_along<P>(self, p: P) -> Along<Self, P>
+        where Scalar: Param, Along<Self, P>: Homotopy<X, Scalar::Float>
+    {
+        Along(self, p)
+    }
+
+    /// Restricts to a sub-box of the parameter cube, rescaled back to the unit cube.
+    ///
+    /// Panics if the sub-box is not inside the unit cube, see `Restrict::new`.
+    fn restrict(&self, start: Scalar, end: Scalar) -> Restrict<&Self, Scalar>
+        where Scalar: Param
+    {
+        Restrict::new(self, start, end)
+    }
+
+    /// Restricts to a sub-box of the parameter cube, rescaled back to the unit cube.
+    ///
+    /// Panics if the sub-box is not inside the unit cube, see `Restrict::new`.
+    fn into_restrict(self, start: Scalar, end: Scalar) -> Restrict<Self, Scalar>
+        where Scalar: Param
+    {
+        Restrict::new(self, start, end)
+    }
+
+    /// Permutes the axes, such that axis `i` of the result is axis `axes[i]`.
+    ///
+    /// Panics if the axes are not a permutation.
+    fn permute<'a, const N: usize>(&'a self, axes: [usize; N]) -> Permute<&'a Self, N>
+        where Permute<&'a Self, N>: Homotopy<X, Scalar>
+    {
+        Permute::new(self, axes)
+    }
+
+    /// Permutes the axes, such that axis `i` of the result is axis `axes[i]`.
+    ///
+    /// Panics if the axes are not a permutation.
+    fn into_permute<const N: usize>(self, axes: [usize; N]) -> Permute<Self, N>
+        where Permute<Self, N>: Homotopy<X, Scalar>
+    {
+        Permute::new(self, axes)
+    }
+
+    /// Reflects some axes, using `1.0 - s` for each reflected axis.
+    fn reflect<'a, const N: usize>(&'a self, axes: [bool; N]) -> Reflect<&'a Self, N>
+        where Reflect<&'a Self, N>: Homotopy<X, Scalar>
+    {
+        Reflect {shape: self, axes}
+    }
+
+    /// Reflects some axes, using `1.0 - s` for each reflected axis.
+    fn into_reflect<const N: usize>(self, axes: [bool; N]) -> Reflect<Self, N>
+        where Reflect<Self, N>: Homotopy<X, Scalar>
+    {
+        Reflect {shape: self, axes}
+    }
+
     /// Gets the left side.
     fn left<'a, S>(&'a self) -> Left<&'a Self>
-        where Left<&'a Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Left<&'a Self>: Homotopy<X, S>
     {
         Left(self)
     }
 
     /// Gets the left side.
     fn into_left<S>(self) -> Left<Self>
-        where Left<Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Left<Self>: Homotopy<X, S>
     {
         Left(self)
     }
 
     /// Gets the right side.
     fn right<'a, S>(&'a self) -> Right<&'a Self>
-        where Right<&'a Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Right<&'a Self>: Homotopy<X, S>
     {
         Right(self)
     }
 
     /// Gets the right side.
     fn into_right<S>(self) -> Right<Self>
-        where Right<Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Right<Self>: Homotopy<X, S>
     {
         Right(self)
     }
 
     /// Gets the top side.
     fn top<'a, S>(&'a self) -> Top<&'a Self>
-        where Top<&'a Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Top<&'a Self>: Homotopy<X, S>
     {
         Top(self)
     }
 
     /// Gets the top side.
     fn into_top<S>(self) -> Top<Self>
-        where Top<Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Top<Self>: Homotopy<X, S>
     {
         Top(self)
     }
 
     /// Gets the bottom side.
     fn bottom<'a, S>(&'a self) -> Bottom<&'a Self>
-        where Bottom<&'a Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Bottom<&'a Self>: Homotopy<X, S>
     {
         Bottom(self)
     }
 
     /// Gets the bottom side.
     fn into_bottom<S>(self) -> Bottom<Self>
-        where Bottom<Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Bottom<Self>: Homotopy<X, S>
     {
         Bottom(self)
     }
 
     /// Gets the front side.
     fn front<'a, S>(&'a self) -> Front<&'a Self>
-        where Front<&'a Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Front<&'a Self>: Homotopy<X, S>
     {
         Front(self)
     }
 
     /// Gets the front side.
     fn into_front<S>(self) -> Front<Self>
-        where Front<Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Front<Self>: Homotopy<X, S>
     {
         Front(self)
     }
 
     /// Gets the back side.
     fn back<'a, S>(&'a self) -> Back<&'a Self>
-        where Back<&'a Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Back<&'a Self>: Homotopy<X, S>
     {
         Back(self)
     }
 
     /// Gets the back side.
     fn into_back<S>(self) -> Back<Self>
-        where Back<Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Back<Self>: Homotopy<X, S>
     {
         Back(self)
     }
 
     /// Gets the past side.
     ///
-    /// This is for 4D homotopy maps.
+    /// This is for 4D and higher homotopy maps.
     fn past<'a, S>(&'a self) -> Past<&'a Self>
-        where Past<&'a Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Past<&'a Self>: Homotopy<X, S>
     {
         Past(self)
     }
 
     /// Gets the past side.
     ///
-    /// This is for 4D homotopy maps.
+    /// This is for 4D and higher homotopy maps.
     fn into_past<S>(self) -> Past<Self>
-        where Past<Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Past<Self>: Homotopy<X, S>
     {
         Past(self)
     }
 
     /// Gets the future side.
     ///
-    /// This is for 4D homotopy maps.
+    /// This is for 4D and higher homotopy maps.
     fn future<'a, S>(&'a self) -> Future<&'a Self>
-        where Future<&'a Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Future<&'a Self>: Homotopy<X, S>
     {
         Future(self)
     }
 
     /// Gets the future side.
     ///
-    /// This is for 4D homotopy maps.
+    /// This is for 4D and higher homotopy maps.
     fn into_future<S>(self) -> Future<Self>
-        where Future<Self>: Homotopy<X, S>
+        where Scalar: Lower<Output = S>, Future<Self>: Homotopy<X, S>
     {
         Future(self)
     }
 
     /// Gets a left-right intersection, controlled by `s`.
-    fn left_right<'a, S>(&'a self, s: f64) -> LeftRight<&'a Self>
-        where LeftRight<&'a Self>: Homotopy<X, S>
+    fn left_right<'a, S>(&'a self, s: Scalar::Float) -> LeftRight<&'a Self, Scalar::Float>
+        where Scalar: Lower<Output = S>, LeftRight<&'a Self, Scalar::Float>: Homotopy<X, S>
     {
         LeftRight(self, s)
     }
 
     /// Gets a left-right intersection, controlled by `s`.
-    fn into_left_right<S>(self, s: f64) -> LeftRight<Self>
-        where LeftRight<Self>: Homotopy<X, S>
+    fn into_left_right<S>(self, s: Scalar::Float) -> LeftRight<Self, Scalar::Float>
+        where Scalar: Lower<Output = S>, LeftRight<Self, Scalar::Float>: Homotopy<X, S>
     {
         LeftRight(self, s)
     }
 
     /// Gets a top-bottom intersection, controlled by `s`.
-    fn top_bottom<'a, S>(&'a self, s: f64) -> TopBottom<&'a Self>
-        where TopBottom<&'a Self>: Homotopy<X, S>
+    fn top_bottom<'a, S>(&'a self, s: Scalar::Float) -> TopBottom<&'a Self, Scalar::Float>
+        where Scalar: Lower<Output = S>, TopBottom<&'a Self, Scalar::Float>: Homotopy<X, S>
     {
         TopBottom(self, s)
     }
 
     /// Gets a top-bottom intersection, controlled by `s`.
-    fn into_top_bottom<S>(self, s: f64) -> TopBottom<Self>
-        where TopBottom<Self>: Homotopy<X, S>
+    fn into_top_bottom<S>(self, s: Scalar::Float) -> TopBottom<Self, Scalar::Float>
+        where Scalar: Lower<Output = S>, TopBottom<Self, Scalar::Float>: Homotopy<X, S>
     {
         TopBottom(self, s)
     }
 
     /// Gets a front-back intersection, controlled by `s`.
-    fn front_back<'a, S>(&'a self, s: f64) -> FrontBack<&'a Self>
-        where FrontBack<&'a Self>: Homotopy<X, S>
+    fn front_back<'a, S>(&'a self, s: Scalar::Float) -> FrontBack<&'a Self, Scalar::Float>
+        where Scalar: Lower<Output = S>, FrontBack<&'a Self, Scalar::Float>: Homotopy<X, S>
     {
         FrontBack(self, s)
     }
 
     /// Gets a front-back intersection, controlled by `s`.
-    fn into_front_back<S>(self, s: f64) -> FrontBack<Self>
-        where FrontBack<Self>: Homotopy<X, S>
+    fn into_front_back<S>(self, s: Scalar::Float) -> FrontBack<Self, Scalar::Float>
+        where Scalar: Lower<Output = S>, FrontBack<Self, Scalar::Float>: Homotopy<X, S>
     {
         FrontBack(self, s)
     }
 
     /// Gets a past-future intersection, controlled by `s`.
-    fn past_future<'a, S>(&'a self, s: f64) -> PastFuture<&'a Self>
-        where PastFuture<&'a Self>: Homotopy<X, S>
+    fn past_future<'a, S>(&'a self, s: Scalar::Float) -> PastFuture<&'a Self, Scalar::Float>
+        where Scalar: Lower<Output = S>, PastFuture<&'a Self, Scalar::Float>: Homotopy<X, S>
     {
         PastFuture(self, s)
     }
 
     /// Gets a past-future intersection, controlled by `s`.
-    fn into_past_future<S>(self, s: f64) -> PastFuture<Self>
-        where PastFuture<Self>: Homotopy<X, S>
+    fn into_past_future<S>(self, s: Scalar::Float) -> PastFuture<Self, Scalar::Float>
+        where Scalar: Lower<Output = S>, PastFuture<Self, Scalar::Float>: Homotopy<X, S>
     {
         PastFuture(self, s)
     }
 
-    /// Gets a converter to and from vectors.
-    fn as_vec<'a, S, VX>(&'a self) -> AsVec<&'a Self>
-        where AsVec<&'a Self>: Homotopy<VX, S>
+    /// Gets the side at the start or end of some axis.
+    fn face<const AXIS: usize, const END: bool>(&self) -> Face<&Self, AXIS, END> {
+        Face(self)
+    }
+
+    /// Gets the side at the start or end of some axis.
+    fn into_face<const AXIS: usize, const END: bool>(self) -> Face<Self, AXIS, END> {Face(self)}
+
+    /// Gets an intersection along some axis, controlled by `s`.
+    fn slice<const AXIS: usize>(&self, s: Scalar::Float) -> Slice<&Self, AXIS, Scalar::Float>
+        where Scalar: Param
     {
-        AsVec(self)
+        Slice(self, s)
     }
 
-    /// Gets a converter to and from vectors.
-    fn into_as_vec<S, VX>(self) -> AsVec<Self>
-        where AsVec<Self>: Homotopy<VX, S>
+    /// Gets an intersection along some axis, controlled by `s`.
+    fn into_slice<const AXIS: usize>(self, s: Scalar::Float) -> Slice<Self, AXIS, Scalar::Float>
+        where Scalar: Param
     {
-        AsVec(self)
+        Slice(self, s)
     }
 
+    /// Gets all sides, two for each axis.
+    ///
+    /// The sides are ordered by axis, with the start before the end.
+    fn sides(&self) -> Vec<Side<&Self>>
+        where Scalar: Param
+    {
+        (0..Scalar::DIM * 2).map(|i| Side {shape: self, axis: i / 2, end: i % 2 == 1}).collect()
+    }
+
+    /// Gets a converter to and from vectors.
+    fn as_vec(&self) -> AsVec<&Self, Scalar> {AsVec::new(self)}
+
+    /// Gets a converter to and from vectors.
+    fn into_as_vec(self) -> AsVec<Self, Scalar> {AsVec::new(self)}
+
     /// Maps output from one to another.
     fn map<'a, F: Fn(Self::Y) -> Y2, Y2>(&'a self, f: F) -> Map<&'a Self, F, Self::Y, Y2>
         where Map<&'a Self, F, Self::Y, Y2>: Homotopy<X, Scalar>
@@ -250,21 +405,75 @@ pub trait Homotopy<X, Scalar=f64>: Sized {
     }
 
     /// Maps output from one to another, into a N+1 homotopy.
-    fn smap<'a, F: Fn(Self::Y, f64) -> Y2, Y2>(&'a self, f: F)
-    -> SMap<&'a Self, F, Self::Y, Y2, f64>
+    fn smap<F: Fn(Self::Y, Scalar::Float) -> Y2, Y2>(&self, f: F)
+    -> SMap<&Self, F, Self::Y, Y2, Scalar>
+        where Scalar: Param
     {
         SMap::new(self, f)
     }
 
     /// Maps output from one to another, into a N+1 homotopy.
-    fn into_smap<F: Fn(Self::Y, f64) -> Y2, Y2>(self, f: F)
-    -> SMap<Self, F, Self::Y, Y2, f64>
+    fn into_smap<F: Fn(Self::Y, Scalar::Float) -> Y2, Y2>(self, f: F)
+    -> SMap<Self, F, Self::Y, Y2, Scalar>
+        where Scalar: Param
     {
         SMap::new(self, f)
     }
+
+    /// Maps input from another form before calling this.
+    fn contramap<'a, F: Fn(X2) -> X, X2>(&'a self, f: F) -> Contramap<&'a Self, F, X, X2>
+        where Contramap<&'a Self, F, X, X2>: Homotopy<X2, Scalar>
+    {
+        Contramap::new(self, f)
+    }
+
+    /// Maps input from another form before calling this.
+    fn into_contramap<F: Fn(X2) -> X, X2>(self, f: F) -> Contramap<Self, F, X, X2>
+        where Contramap<Self, F, X, X2>: Homotopy<X2, Scalar>
+    {
+        Contramap::new(self, f)
+    }
+
+    /// Runs side by side with another homotopy, pairing the outputs.
+    fn zip<'a, B>(&'a self, b: B) -> Zip<&'a Self, B>
+        where Zip<&'a Self, B>: Homotopy<X, Scalar>
+    {
+        Zip(self, b)
+    }
+
+    /// Runs side by side with another homotopy, pairing the outputs.
+    fn into_zip<B>(self, b: B) -> Zip<Self, B>
+        where Zip<Self, B>: Homotopy<X, Scalar>
+    {
+        Zip(self, b)
+    }
+
+    /// Enables the operators `+`, `-` and `*`, e.g. `a.ops() + b`.
+    ///
+    /// The homotopies of this crate support the operators directly, e.g. `a + b`.
+    fn ops(&self) -> Ops<&Self> {Ops(self)}
+
+    /// Enables the operators `+`, `-` and `*`, e.g. `a.into_ops() + b`.
+    ///
+    /// The homotopies of this crate support the operators directly, e.g. `a + b`.
+    fn into_ops(self) -> Ops<Self> {Ops(self)}
+
+    /// Converts into a boxed `DynHomotopy`, with the dimension known at runtime.
+    fn into_box<'a>(self) -> Box<dyn DynHomotopy<X, Self::Y> + 'a>
+        where Self: 'a, Scalar: Param + 'a
+    {
+        Box::new(Dyn::<Self, Scalar>::new(self))
+    }
+
+    /// Converts into a shared `DynHomotopy`, with the dimension known at runtime.
+    fn into_arc<'a>(self) -> Arc<dyn DynHomotopy<X, Self::Y> + 'a>
+        where Self: 'a, Scalar: Param + 'a
+    {
+        Arc::new(Dyn::<Self, Scalar>::new(self))
+    }
 }
 
-impl<'a, X, T, S> Homotopy<X, S> for &'a T
+impl<X, T, S> Homotopy<X, S> for &T
     where T: Homotopy<X, S>
 {
     type Y = T::Y;
@@ -276,138 +485,157 @@ impl<'a, X, T, S> Homotopy<X, S> for &'a T
 
 /// Checks that the homotopy constraints hold for some input `x`.
 #[must_use]
-pub fn check<H, X>(h: &H, x: X) -> bool
-    where H: Homotopy<X>,
+pub fn check<H, X, F>(h: &H, x: X) -> bool
+    where F: Float,
+          H: Homotopy<X, F>,
           H::Y: PartialEq,
           X: Clone
 {
-    h.h(x.clone(), 0.0) == h.f(x.clone()) &&
-    h.h(x.clone(), 1.0) == h.g(x)
+    h.h(x.clone(), F::zero()) == h.f(x.clone()) &&
+    h.h(x.clone(), F::one()) == h.g(x)
 }
 
 /// Checks that the homotopy constraints hold for default input.
 #[must_use]
-pub fn checku<H, X>(h: &H) -> bool
-    where H: Homotopy<X>,
+pub fn checku<H, X, F>(h: &H) -> bool
+    where F: Float,
+          H: Homotopy<X, F>,
           H::Y: PartialEq,
           X: Default
 {
-    h.hu(0.0) == h.f(Default::default()) &&
-    h.hu(1.0) == h.g(Default::default())
+    h.hu(F::zero()) == h.f(Default::default()) &&
+    h.hu(F::one()) == h.g(Default::default())
+}
+
+/// Visits the start and end corner of every side of every dimension,
+/// including the homotopy itself.
+///
+/// The input is created by `x` for every call to `h`, such that it need not be `Clone`.
+/// `visit` receives the fixed axes of the side, whether it is the end corner,
+/// the parameter of the corner, the output of `h` and the expected output.
+/// Stops and returns `false` when `visit` returns `false`.
+fn visit_sides<H, X, S, G, F>(h: &H, x: G, mut visit: F) -> bool
+    where H: Homotopy<X, S>,
+          S: Param,
+          G: Fn() -> X,
+          F: FnMut(&[Option<f64>], bool, S, H::Y, H::Y) -> bool
+{
+    let n = S::DIM;
+    let mut fixed = vec![None; n];
+    // Each side is encoded in base 3 per axis: free, fixed at 0.0 or fixed at 1.0.
+    for side in 0..3usize.pow(n as u32) {
+        for (axis, v) in fixed.iter_mut().enumerate() {
+            *v = match side / 3usize.pow(axis as u32) % 3 {
+                0 => None,
+                1 => Some(0.0),
+                _ => Some(1.0),
+            };
+        }
+        if fixed.iter().all(|v| v.is_some()) {continue};
+
+        let start = S::from_fn(|i| S::Float::from_f64(fixed[i].unwrap_or(0.0)));
+        let a = if fixed.iter().all(|&v| v != Some(1.0)) {h.f(x())}
+                else {h.h(x(), start)};
+        if !visit(&fixed, false, start, h.h(x(), start), a) {return false};
+
+        let end = S::from_fn(|i| S::Float::from_f64(fixed[i].unwrap_or(1.0)));
+        let b = if fixed.iter().all(|&v| v != Some(0.0)) {h.g(x())}
+                else {h.h(x(), end)};
+        if !visit(&fixed, true, end, h.h(x(), end), b) {return false};
+    }
+    true
+}
+
+/// Checks that the N-dimensional homotopy constraints hold for some input `x`.
+///
+/// This checks every side of every dimension, e.g. the sides of a 3D homotopy
+/// and the sides of those sides.
+/// It works for any dimension, see `Shape::DIM`.
+#[must_use]
+pub fn checkn<H, X, S>(h: &H, x: X) -> bool
+    where H: Homotopy<X, S>,
+          H::Y: PartialEq,
+          X: Clone,
+          S: Param,
+{
+    visit_sides(h, || x.clone(), |_, _, _, observed, expected| observed == expected)
+}
+
+/// Checks that the N-dimensional homotopy constraints hold for default input.
+#[must_use]
+pub fn checkun<H, X, S>(h: &H) -> bool
+    where H: Homotopy<X, S>,
+          H::Y: PartialEq,
+          X: Default,
+          S: Param,
+{
+    visit_sides(h, X::default, |_, _, _, observed, expected| observed == expected)
 }
 
 /// Checks that the 2D homotopy constraints hold for some input `x`.
 #[must_use]
-pub fn check2<H, X>(h: &H, x: X) -> bool
-    where H: Homotopy<X, [f64; 2]>,
+pub fn check2<H, X, F>(h: &H, x: X) -> bool
+    where F: Float,
+          H: Homotopy<X, [F; 2]>,
           H::Y: PartialEq,
           X: Clone,
 {
-    let a = h.f(x.clone());
-    let b = h.g(x.clone());
-    h.h(x.clone(), [0.0, 0.0]) == a &&
-    h.h(x.clone(), [1.0, 1.0]) == b &&
-    check(&h.left(), x.clone()) &&
-    check(&h.right(), x.clone()) &&
-    check(&h.top(), x.clone()) &&
-    check(&h.bottom(), x.clone())
+    checkn(h, x)
 }
 
 /// Checks that the 2D homotopy constraints hold for default input.
 #[must_use]
-pub fn checku2<H, X>(h: &H) -> bool
-    where H: Homotopy<X, [f64; 2]>,
+pub fn checku2<H, X, F>(h: &H) -> bool
+    where F: Float,
+          H: Homotopy<X, [F; 2]>,
           H::Y: PartialEq,
           X: Default,
 {
-    let a = h.f(Default::default());
-    let b = h.g(Default::default());
-    h.hu([0.0, 0.0]) == a &&
-    h.hu([1.0, 1.0]) == b &&
-    checku(&h.left()) &&
-    checku(&h.right()) &&
-    checku(&h.top()) &&
-    checku(&h.bottom())
+    checkun(h)
 }
 
 /// Checks that the 3D homotopy constraints hold for some input `x`.
 #[must_use]
-pub fn check3<H, X>(h: &H, x: X) -> bool
-    where H: Homotopy<X, [f64; 3]>,
+pub fn check3<H, X, F>(h: &H, x: X) -> bool
+    where F: Float,
+          H: Homotopy<X, [F; 3]>,
           H::Y: PartialEq,
           X: Clone,
 {
-    let a = h.f(x.clone());
-    let b = h.g(x.clone());
-    h.h(x.clone(), [0.0, 0.0, 0.0]) == a &&
-    h.h(x.clone(), [1.0, 1.0, 1.0]) == b &&
-    check2(&h.left(), x.clone()) &&
-    check2(&h.right(), x.clone()) &&
-    check2(&h.top(), x.clone()) &&
-    check2(&h.bottom(), x.clone()) &&
-    check2(&h.front(), x.clone()) &&
-    check2(&h.back(), x.clone())
+    checkn(h, x)
 }
 
 /// Checks that the 3D homotopy constraints hold for default input.
 #[must_use]
-pub fn checku3<H, X>(h: &H) -> bool
-    where H: Homotopy<X, [f64; 3]>,
+pub fn checku3<H, X, F>(h: &H) -> bool
+    where F: Float,
+          H: Homotopy<X, [F; 3]>,
           H::Y: PartialEq,
           X: Default,
 {
-    let a = h.f(Default::default());
-    let b = h.g(Default::default());
-    h.hu([0.0, 0.0, 0.0]) == a &&
-    h.hu([1.0, 1.0, 1.0]) == b &&
-    checku2(&h.left()) &&
-    checku2(&h.right()) &&
-    checku2(&h.top()) &&
-    checku2(&h.bottom()) &&
-    checku2(&h.front()) &&
-    checku2(&h.back())
+    checkun(h)
 }
 
 /// Checks that the 4D homotopy constraints hold for some input `x`.
 #[must_use]
-pub fn check4<H, X>(h: &H, x: X) -> bool
-    where H: Homotopy<X, [f64; 4]>,
+pub fn check4<H, X, F>(h: &H, x: X) -> bool
+    where F: Float,
+          H: Homotopy<X, [F; 4]>,
           H::Y: PartialEq,
           X: Clone,
 {
-    let a = h.f(x.clone());
-    let b = h.g(x.clone());
-    h.h(x.clone(), [0.0; 4]) == a &&
-    h.h(x.clone(), [1.0; 4]) == b &&
-    check3(&h.left(), x.clone()) &&
-    check3(&h.right(), x.clone()) &&
-    check3(&h.top(), x.clone()) &&
-    check3(&h.bottom(), x.clone()) &&
-    check3(&h.front(), x.clone()) &&
-    check3(&h.back(), x.clone()) &&
-    check3(&h.past(), x.clone()) &&
-    check3(&h.future(), x.clone())
+    checkn(h, x)
 }
 
 /// Checks that the 4D homotopy constraints hold for default input.
 #[must_use]
-pub fn checku4<H, X>(h: &H) -> bool
-    where H: Homotopy<X, [f64; 4]>,
+pub fn checku4<H, X, F>(h: &H) -> bool
+    where F: Float,
+          H: Homotopy<X, [F; 4]>,
           H::Y: PartialEq,
           X: Default,
 {
-    let a = h.f(Default::default());
-    let b = h.g(Default::default());
-    h.hu([0.0; 4]) == a &&
-    h.hu([1.0; 4]) == b &&
-    checku3(&h.left()) &&
-    checku3(&h.right()) &&
-    checku3(&h.top()) &&
-    checku3(&h.bottom()) &&
-    checku3(&h.front()) &&
-    checku3(&h.back()) &&
-    checku3(&h.past()) &&
-    checku3(&h.future())
+    checkun(h)
 }
 
 /// Identity homotopy.
@@ -428,12 +656,12 @@ impl<X, S> Homotopy<X, S> for Id {
 #[derive(Copy, Clone)]
 pub struct Dirac;
 
-impl Homotopy<()> for Dirac {
-    type Y = f64;
+impl<F: Float> Homotopy<(), F> for Dirac {
+    type Y = F;
 
-    fn f(&self, _: ()) -> f64 {1.0}
-    fn g(&self, _: ()) -> f64 {0.0}
-    fn h(&self, _: (), s: f64) -> f64 {if s == 0.0 {1.0} else {0.0}}
+    fn f(&self, _: ()) -> F {F::one()}
+    fn g(&self, _: ()) -> F {F::zero()}
+    fn h(&self, _: (), s: F) -> F {if s == F::zero() {F::one()} else {F::zero()}}
 }
 
 /// Dirac From homotopy.
@@ -464,17 +692,18 @@ impl<X, Y, F, G> DiracFrom<X, Y, F, G>
     }
 }
 
-impl<X, Y, F, G> Homotopy<X> for DiracFrom<X, Y, F, G>
+impl<X, Y, F, G, S> Homotopy<X, S> for DiracFrom<X, Y, F, G>
     where Y: Clone,
           F: Fn(X) -> Y,
-          G: Fn(X) -> Y
+          G: Fn(X) -> Y,
+          S: Float
 {
     type Y = Y;
 
     fn f(&self, x: X) -> Y {(self.fx)(x)}
     fn g(&self, x: X) -> Y {(self.gx)(x)}
-    fn h(&self, x: X, s: f64) -> Y {
-        if s == 0.0 {(self.fx)(x)}
+    fn h(&self, x: X, s: S) -> Y {
+        if s == S::zero() {(self.fx)(x)}
         else {(self.gx)(x)}
     }
 }
@@ -486,14 +715,14 @@ impl<X, Y, F, G> Homotopy<X> for DiracFrom<X, Y, F, G>
 #[derive(Copy, Clone)]
 pub struct Lerp<X>(pub X, pub X);
 
-impl<Y> Homotopy<()> for Lerp<Y>
-    where Y: Mul<f64, Output = Y> + Add<Output = Y> + Clone
+impl<Y> Homotopy<(), Y::Scalar> for Lerp<Y>
+    where Y: VectorSpace
 {
     type Y = Y;
 
     fn f(&self, _: ()) -> Y {self.0.clone()}
     fn g(&self, _: ()) -> Y {self.1.clone()}
-    fn h(&self, _: (), s: f64) -> Y {self.0.clone() * (1.0 - s) + self.1.clone() * s}
+    fn h(&self, _: (), s: Y::Scalar) -> Y {self.0.lerp(&self.1, s)}
 }
 
 /// Quadratic Bezier homotopy.
@@ -505,28 +734,28 @@ pub struct QuadraticBezier<X>(pub X, pub X, pub X);
 impl<X> QuadraticBezier<X> {
     /// Creates a quadratic bezier that is identical to linear interpolation.
     pub fn from_linear(a: X, b: X) -> QuadraticBezier<X>
-        where X: Mul<f64, Output = X> + Add<Output = X> + Clone
+        where X: VectorSpace
     {
-        QuadraticBezier(a.clone(), a * 0.5 + b.clone() * 0.5, b)
+        QuadraticBezier(a.clone(), a.lerp(&b, X::Scalar::from_f64(0.5)), b)
     }
 }
 
 impl<X> From<Lerp<X>> for QuadraticBezier<X>
-    where X: Mul<f64, Output = X> + Add<Output = X> + Clone
+    where X: VectorSpace
 {
     fn from(lerp: Lerp<X>) -> QuadraticBezier<X> {
         QuadraticBezier::from_linear(lerp.0, lerp.1)
     }
 }
 
-impl<Y> Homotopy<()> for QuadraticBezier<Y>
-    where Y: Mul<f64, Output = Y> + Add<Output = Y> + Clone
+impl<Y> Homotopy<(), Y::Scalar> for QuadraticBezier<Y>
+    where Y: VectorSpace
 {
     type Y = Y;
 
     fn f(&self, _: ()) -> Y {self.0.clone()}
     fn g(&self, _: ()) -> Y {self.2.clone()}
-    fn h(&self, _: (), s: f64) -> Y {
+    fn h(&self, _: (), s: Y::Scalar) -> Y {
         let a = Lerp(self.0.clone(), self.1.clone()).h((), s);
         let b = Lerp(self.1.clone(), self.2.clone()).h((), s);
         Lerp(a, b).h((), s)
@@ -556,14 +785,14 @@ impl<X> From<QuadraticBezier<X>> for CubicBezier<X>
     }
 }
 
-impl<Y> Homotopy<()> for CubicBezier<Y>
-    where Y: Mul<f64, Output = Y> + Add<Output = Y> + Clone
+impl<Y> Homotopy<(), Y::Scalar> for CubicBezier<Y>
+    where Y: VectorSpace
 {
     type Y = Y;
 
     fn f(&self, _: ()) -> Y {self.0.clone()}
     fn g(&self, _: ()) -> Y {self.3.clone()}
-    fn h(&self, _: (), s: f64) -> Y {
+    fn h(&self, _: (), s: Y::Scalar) -> Y {
         let a = Lerp(self.0.clone(), self.1.clone()).h((), s);
         let b = Lerp(self.2.clone(), self.3.clone()).h((), s);
         Lerp(a, b).h((), s)
@@ -572,39 +801,33 @@ impl<Y> Homotopy<()> for CubicBezier<Y>
 
 /// Takes the square of two homotopy maps and produces a 2D homotopy.
 #[derive(Copy, Clone)]
-pub struct Square<X1, X2, H1, H2>
-    where H1: Homotopy<X1>, H2: Homotopy<X2>
-{
+pub struct Square<X1, X2, H1, H2> {
     h1: H1,
     h2: H2,
     _x1: PhantomData<X1>,
     _x2: PhantomData<X2>,
 }
 
-impl<X1, X2, H1, H2> Square<X1, X2, H1, H2>
-    where H1: Homotopy<X1>, H2: Homotopy<X2>
-{
+impl<X1, X2, H1, H2> Square<X1, X2, H1, H2> {
     /// Creates a square of two homotopy maps.
     pub fn new(h1: H1, h2: H2) -> Self {
         Square {h1, h2, _x1: PhantomData, _x2: PhantomData}
     }
 }
 
-impl<X1, X2, H1, H2> Homotopy<(X1, X2), [f64; 2]> for Square<X1, X2, H1, H2>
-    where H1: Homotopy<X1>, H2: Homotopy<X2>
+impl<X1, X2, H1, H2, F> Homotopy<(X1, X2), [F; 2]> for Square<X1, X2, H1, H2>
+    where F: Float, H1: Homotopy<X1, F>, H2: Homotopy<X2, F>
 {
     type Y = (H1::Y, H2::Y);
 
     fn f(&self, x: (X1, X2)) -> Self::Y {(self.h1.f(x.0), self.h2.f(x.1))}
     fn g(&self, x: (X1, X2)) -> Self::Y {(self.h1.g(x.0), self.h2.g(x.1))}
-    fn h(&self, x: (X1, X2), s: [f64; 2]) -> Self::Y {(self.h1.h(x.0, s[0]), self.h2.h(x.1, s[1]))}
+    fn h(&self, x: (X1, X2), s: [F; 2]) -> Self::Y {(self.h1.h(x.0, s[0]), self.h2.h(x.1, s[1]))}
 }
 
 /// Takes the cube of three homotopy maps and produces a 3D homotopy.
 #[derive(Copy, Clone)]
-pub struct Cube<X1, X2, X3, H1, H2, H3>
-    where H1: Homotopy<X1>, H2: Homotopy<X2>, H3: Homotopy<X3>
-{
+pub struct Cube<X1, X2, X3, H1, H2, H3> {
     h1: H1,
     h2: H2,
     h3: H3,
@@ -613,32 +836,29 @@ pub struct Cube<X1, X2, X3, H1, H2, H3>
     _x3: PhantomData<X3>,
 }
 
-impl<X1, X2, X3, H1, H2, H3> Cube<X1, X2, X3, H1, H2, H3>
-    where H1: Homotopy<X1>, H2: Homotopy<X2>, H3: Homotopy<X3>
-{
+impl<X1, X2, X3, H1, H2, H3> Cube<X1, X2, X3, H1, H2, H3> {
     /// Creates a new cube of three homotopy maps.
     pub fn new(h1: H1, h2: H2, h3: H3) -> Self {
         Cube {h1, h2, h3, _x1: PhantomData, _x2: PhantomData, _x3: PhantomData}
     }
 }
 
-impl<X1, X2, X3, H1, H2, H3> Homotopy<(X1, X2, X3), [f64; 3]> for Cube<X1, X2, X3, H1, H2, H3>
-    where H1: Homotopy<X1>, H2: Homotopy<X2>, H3: Homotopy<X3>
+impl<X1, X2, X3, H1, H2, H3, F> Homotopy<(X1, X2, X3), [F; 3]>
+for Cube<X1, X2, X3, H1, H2, H3>
+    where F: Float, H1: Homotopy<X1, F>, H2: Homotopy<X2, F>, H3: Homotopy<X3, F>
 {
     type Y = (H1::Y, H2::Y, H3::Y);
 
     fn f(&self, x: (X1, X2, X3)) -> Self::Y {(self.h1.f(x.0), self.h2.f(x.1), self.h3.f(x.2))}
     fn g(&self, x: (X1, X2, X3)) -> Self::Y {(self.h1.g(x.0), self.h2.g(x.1), self.h3.g(x.2))}
-    fn h(&self, x: (X1, X2, X3), s: [f64; 3]) -> Self::Y {
+    fn h(&self, x: (X1, X2, X3), s: [F; 3]) -> Self::Y {
         (self.h1.h(x.0, s[0]), self.h2.h(x.1, s[1]), self.h3.h(x.2, s[2]))
     }
 }
 
 /// Takes the 4-cube of four homotopy maps and produces a 4D homotopy.
 #[derive(Copy, Clone)]
-pub struct Cube4<X1, X2, X3, X4, H1, H2, H3, H4>
-    where H1: Homotopy<X1>, H2: Homotopy<X2>, H3: Homotopy<X3>, H4: Homotopy<X4>
-{
+pub struct Cube4<X1, X2, X3, X4, H1, H2, H3, H4> {
     h1: H1,
     h2: H2,
     h3: H3,
@@ -649,9 +869,7 @@ pub struct Cube4<X1, X2, X3, X4, H1, H2, H3, H4>
     _x4: PhantomData<X4>,
 }
 
-impl<X1, X2, X3, X4, H1, H2, H3, H4> Cube4<X1, X2, X3, X4, H1, H2, H3, H4>
-    where H1: Homotopy<X1>, H2: Homotopy<X2>, H3: Homotopy<X3>, H4: Homotopy<X4>
-{
+impl<X1, X2, X3, X4, H1, H2, H3, H4> Cube4<X1, X2, X3, X4, H1, H2, H3, H4> {
     /// Creates a new 4-cube of four homotopy maps.
     pub fn new(h1: H1, h2: H2, h3: H3, h4: H4) -> Self {
         Cube4 {
@@ -664,9 +882,13 @@ impl<X1, X2, X3, X4, H1, H2, H3, H4> Cube4<X1, X2, X3, X4, H1, H2, H3, H4>
     }
 }
 
-impl<X1, X2, X3, X4, H1, H2, H3, H4> Homotopy<(X1, X2, X3, X4), [f64; 4]>
+impl<X1, X2, X3, X4, H1, H2, H3, H4, F> Homotopy<(X1, X2, X3, X4), [F; 4]>
 for Cube4<X1, X2, X3, X4, H1, H2, H3, H4>
-    where H1: Homotopy<X1>, H2: Homotopy<X2>, H3: Homotopy<X3>, H4: Homotopy<X4>
+    where F: Float,
+          H1: Homotopy<X1, F>,
+          H2: Homotopy<X2, F>,
+          H3: Homotopy<X3, F>,
+          H4: Homotopy<X4, F>,
 {
     type Y = (H1::Y, H2::Y, H3::Y, H4::Y);
 
@@ -676,7 +898,7 @@ for Cube4<X1, X2, X3, X4, H1, H2, H3, H4>
     fn g(&self, x: (X1, X2, X3, X4)) -> Self::Y {
         (self.h1.g(x.0), self.h2.g(x.1), self.h3.g(x.2), self.h4.g(x.3))
     }
-    fn h(&self, x: (X1, X2, X3, X4), s: [f64; 4]) -> Self::Y {
+    fn h(&self, x: (X1, X2, X3, X4), s: [F; 4]) -> Self::Y {
         (self.h1.h(x.0, s[0]), self.h2.h(x.1, s[1]), self.h3.h(x.2, s[2]), self.h4.h(x.3, s[3]))
     }
 }
@@ -684,73 +906,86 @@ for Cube4<X1, X2, X3, X4, H1, H2, H3, H4>
 /// Inverts the direction of a homotopy.
 pub struct Inverse<T>(pub T);
 
-impl<X, T> Homotopy<X> for Inverse<T>
-    where T: Homotopy<X>
+impl<X, T, F> Homotopy<X, F> for Inverse<T>
+    where F: Float, T: Homotopy<X, F>
 {
     type Y = T::Y;
 
     fn f(&self, x: X) -> Self::Y {self.0.g(x)}
     fn g(&self, x: X) -> Self::Y {self.0.f(x)}
-    fn h(&self, x: X, s: f64) -> Self::Y {self.0.h(x, 1.0 - s)}
+    fn h(&self, x: X, s: F) -> Self::Y {self.0.h(x, F::one() - s)}
 }
 
 /// Converts to and from vectors.
+///
+/// The parameter type `S` is kept, such that it can be inferred from later use,
+/// e.g. for `Id` which works with any parameter type.
 #[derive(Copy, Clone)]
-pub struct AsVec<T>(pub T);
+pub struct AsVec<T, S> {
+    pub(crate) shape: T,
+    _s: PhantomData<S>,
+}
 
-impl<X, Y, S, T> Homotopy<[X; 2], S> for AsVec<T>
+impl<T, S> AsVec<T, S> {
+    /// Creates a new converter to and from vectors.
+    pub fn new(shape: T) -> Self {
+        AsVec {shape, _s: PhantomData}
+    }
+}
+
+impl<X, Y, S, T> Homotopy<[X; 2], S> for AsVec<T, S>
     where T: Homotopy<(X, X), S, Y = (Y, Y)>, X: Copy
 {
     type Y = [Y; 2];
 
     fn f(&self, x: [X; 2]) -> Self::Y {
-        let (a, b) = self.0.f((x[0], x[1]));
+        let (a, b) = self.shape.f((x[0], x[1]));
         [a, b]
     }
     fn g(&self, x: [X; 2]) -> Self::Y {
-        let (a, b) = self.0.g((x[0], x[1]));
+        let (a, b) = self.shape.g((x[0], x[1]));
         [a, b]
     }
     fn h(&self, x: [X; 2], s: S) -> Self::Y {
-        let (a, b) = self.0.h((x[0], x[1]), s);
+        let (a, b) = self.shape.h((x[0], x[1]), s);
         [a, b]
     }
 }
 
-impl<X, Y, S, T> Homotopy<[X; 3], S> for AsVec<T>
+impl<X, Y, S, T> Homotopy<[X; 3], S> for AsVec<T, S>
     where T: Homotopy<(X, X, X), S, Y = (Y, Y, Y)>, X: Copy
 {
     type Y = [Y; 3];
 
     fn f(&self, x: [X; 3]) -> Self::Y {
-        let (a, b, c) = self.0.f((x[0], x[1], x[2]));
+        let (a, b, c) = self.shape.f((x[0], x[1], x[2]));
         [a, b, c]
     }
     fn g(&self, x: [X; 3]) -> Self::Y {
-        let (a, b, c) = self.0.g((x[0], x[1], x[2]));
+        let (a, b, c) = self.shape.g((x[0], x[1], x[2]));
         [a, b, c]
     }
     fn h(&self, x: [X; 3], s: S) -> Self::Y {
-        let (a, b, c) = self.0.h((x[0], x[1], x[2]), s);
+        let (a, b, c) = self.shape.h((x[0], x[1], x[2]), s);
         [a, b, c]
     }
 }
 
-impl<X, Y, S, T> Homotopy<[X; 4], S> for AsVec<T>
+impl<X, Y, S, T> Homotopy<[X; 4], S> for AsVec<T, S>
     where T: Homotopy<(X, X, X, X), S, Y = (Y, Y, Y, Y)>, X: Copy
 {
     type Y = [Y; 4];
 
     fn f(&self, x: [X; 4]) -> Self::Y {
-        let (a, b, c, d) = self.0.f((x[0], x[1], x[2], x[3]));
+        let (a, b, c, d) = self.shape.f((x[0], x[1], x[2], x[3]));
         [a, b, c, d]
     }
     fn g(&self, x: [X; 4]) -> Self::Y {
-        let (a, b, c, d) = self.0.g((x[0], x[1], x[2], x[3]));
+        let (a, b, c, d) = self.shape.g((x[0], x[1], x[2], x[3]));
         [a, b, c, d]
     }
     fn h(&self, x: [X; 4], s: S) -> Self::Y {
-        let (a, b, c, d) = self.0.h((x[0], x[1], x[2], x[3]), s);
+        let (a, b, c, d) = self.shape.h((x[0], x[1], x[2], x[3]), s);
         [a, b, c, d]
     }
 }
@@ -764,31 +999,33 @@ pub struct Circle<T> {
     pub radius: T
 }
 
-impl<T> Homotopy<()> for Circle<T>
-    where T: Clone + Add<Output = T> + Sub<Output = T> + Mul<f64, Output = T>
+impl<T> Homotopy<(), T::Scalar> for Circle<T>
+    where T: VectorSpace
 {
     type Y = [T; 2];
 
     fn f(&self, _: ()) -> Self::Y {
-        [self.center[0].clone() + self.radius.clone(), self.center[1].clone()]
+        [self.center[0].add_vector(&self.radius), self.center[1].clone()]
     }
     fn g(&self, _: ()) -> Self::Y {
-        [self.center[0].clone() + self.radius.clone(), self.center[1].clone()]
+        [self.center[0].add_vector(&self.radius), self.center[1].clone()]
     }
-    fn h(&self, _: (), s: f64) -> Self::Y {
+    fn h(&self, _: (), s: T::Scalar) -> Self::Y {
+        let c = T::Scalar::from_f64;
         // Handle special cases to get exact values.
-        if s == 1.0 {
+        if s == c(1.0) {
             return self.g(())
-        } else if s == 0.5 {
-            return [self.center[0].clone() - self.radius.clone(), self.center[1].clone()]
-        } else if s == 0.25 {
-            return [self.center[0].clone(), self.center[1].clone() + self.radius.clone()]
-        } else if s == 0.75 {
-            return [self.center[0].clone(), self.center[1].clone() - self.radius.clone()]
+        } else if s == c(0.5) {
+            return [self.center[0].sub_vector(&self.radius), self.center[1].clone()]
+        } else if s == c(0.25) {
+            return [self.center[0].clone(), self.center[1].add_vector(&self.radius)]
+        } else if s == c(0.75) {
+            return [self.center[0].clone(), self.center[1].sub_vector(&self.radius)]
         };
+        let angle = s * T::Scalar::pi() * c(2.0);
         [
-            self.center[0].clone() + self.radius.clone() * (s * std::f64::consts::PI * 2.0).cos(),
-            self.center[1].clone() + self.radius.clone() * (s * std::f64::consts::PI * 2.0).sin(),
+            self.center[0].add_vector(&self.radius.scale(angle.cos())),
+            self.center[1].add_vector(&self.radius.scale(angle.sin())),
         ]
     }
 }
@@ -829,9 +1066,11 @@ impl<H, F, Y2, X, S> Homotopy<X, S> for Map<H, F, H::Y, Y2>
 ///
 /// This is used when the output contains extra structure you want to interpolate over,
 /// or when you want to extend the shape along some new dimension.
+///
+/// `S` is the parameter type of the N-homotopy map.
 #[derive(Clone, Copy)]
 pub struct SMap<H, F, Y1, Y2, S>
-    where F: Fn(Y1, S) -> Y2
+    where S: Param, F: Fn(Y1, S::Float) -> Y2
 {
     hom: H,
     fun: F,
@@ -841,7 +1080,7 @@ pub struct SMap<H, F, Y1, Y2, S>
 }
 
 impl<H, F, Y1, Y2, S> SMap<H, F, Y1, Y2, S>
-    where F: Fn(Y1, S) -> Y2
+    where S: Param, F: Fn(Y1, S::Float) -> Y2
 {
     /// Creates new map.
     pub fn new(h: H, f: F) -> Self {
@@ -851,34 +1090,64 @@ impl<H, F, Y1, Y2, S> SMap<H, F, Y1, Y2, S>
     }
 }
 
-impl<H, F, Y2, X> Homotopy<X, [f64; 2]> for SMap<H, F, H::Y, Y2, f64>
-    where H: Homotopy<X>, F: Fn(H::Y, f64) -> Y2
+impl<H, F, Y2, X, S> Homotopy<X, S::Output> for SMap<H, F, H::Y, Y2, S>
+    where S: Higher, H: Homotopy<X, S>, F: Fn(H::Y, S::Float) -> Y2
 {
     type Y = Y2;
 
-    fn f(&self, x: X) -> Self::Y {(self.fun)(self.hom.f(x), 0.0)}
-    fn g(&self, x: X) -> Self::Y {(self.fun)(self.hom.g(x), 1.0)}
-    fn h(&self, x: X, s: [f64; 2]) -> Self::Y {(self.fun)(self.hom.h(x, s[0]), s[1])}
+    fn f(&self, x: X) -> Self::Y {(self.fun)(self.hom.f(x), S::Float::zero())}
+    fn g(&self, x: X) -> Self::Y {(self.fun)(self.hom.g(x), S::Float::one())}
+    fn h(&self, x: X, s: S::Output) -> Self::Y {
+        (self.fun)(self.hom.h(x, s.remove(S::DIM)), s.get(S::DIM))
+    }
 }
 
-impl<H, F, Y2, X> Homotopy<X, [f64; 3]> for SMap<H, F, H::Y, Y2, f64>
-    where H: Homotopy<X, [f64; 2]>, F: Fn(H::Y, f64) -> Y2
+/// Maps input of homotopy map from another form.
+#[derive(Clone, Copy)]
+pub struct Contramap<H, F, X1, X2>
+    where F: Fn(X2) -> X1
 {
-    type Y = Y2;
+    hom: H,
+    fun: F,
+    _x1: PhantomData<X1>,
+    _x2: PhantomData<X2>,
+}
 
-    fn f(&self, x: X) -> Self::Y {(self.fun)(self.hom.f(x), 0.0)}
-    fn g(&self, x: X) -> Self::Y {(self.fun)(self.hom.g(x), 1.0)}
-    fn h(&self, x: X, s: [f64; 3]) -> Self::Y {(self.fun)(self.hom.h(x, [s[0], s[1]]), s[2])}
+impl<H, F, X1, X2> Contramap<H, F, X1, X2>
+    where F: Fn(X2) -> X1
+{
+    /// Creates new map.
+    pub fn new(h: H, f: F) -> Self {
+        Contramap {
+            hom: h, fun: f, _x1: PhantomData, _x2: PhantomData
+        }
+    }
 }
 
-impl<H, F, Y2, X> Homotopy<X, [f64; 4]> for SMap<H, F, H::Y, Y2, f64>
-    where H: Homotopy<X, [f64; 3]>, F: Fn(H::Y, f64) -> Y2
+impl<H, F, X1, X2, S> Homotopy<X2, S> for Contramap<H, F, X1, X2>
+    where H: Homotopy<X1, S>, F: Fn(X2) -> X1
 {
-    type Y = Y2;
+    type Y = H::Y;
 
-    fn f(&self, x: X) -> Self::Y {(self.fun)(self.hom.f(x), 0.0)}
-    fn g(&self, x: X) -> Self::Y {(self.fun)(self.hom.g(x), 1.0)}
-    fn h(&self, x: X, s: [f64; 4]) -> Self::Y {(self.fun)(self.hom.h(x, [s[0], s[1], s[2]]), s[3])}
+    fn f(&self, x: X2) -> Self::Y {self.hom.f((self.fun)(x))}
+    fn g(&self, x: X2) -> Self::Y {self.hom.g((self.fun)(x))}
+    fn h(&self, x: X2, s: S) -> Self::Y {self.hom.h((self.fun)(x), s)}
+}
+
+/// Runs two homotopy maps side by side with the same input and scalar.
+///
+/// Unlike `Square`, the scalar is shared, so the result has the same dimension.
+#[derive(Clone, Copy)]
+pub struct Zip<A, B>(pub A, pub B);
+
+impl<A, B, X, S> Homotopy<X, S> for Zip<A, B>
+    where A: Homotopy<X, S>, B: Homotopy<X, S>, X: Clone, S: Copy
+{
+    type Y = (A::Y, B::Y);
+
+    fn f(&self, x: X) -> Self::Y {(self.0.f(x.clone()), self.1.f(x))}
+    fn g(&self, x: X) -> Self::Y {(self.0.g(x.clone()), self.1.g(x))}
+    fn h(&self, x: X, s: S) -> Self::Y {(self.0.h(x.clone(), s), self.1.h(x, s))}
 }
 
 impl<T, S> Homotopy<usize, S> for Vec<T>
@@ -905,76 +1174,29 @@ impl<T, S, X> Homotopy<(usize, X), S> for Vec<T>
 #[derive(Copy, Clone)]
 pub struct Translate<X>(pub X);
 
-impl Homotopy<f64> for Translate<f64> {
-    type Y = f64;
-
-    fn f(&self, x: f64) -> Self::Y {x}
-    fn g(&self, x: f64) -> Self::Y {x + self.0}
-    fn h(&self, x: f64, s: f64) -> Self::Y {x + s * self.0}
-}
-
-impl Homotopy<[f64; 2]> for Translate<[f64; 2]> {
-    type Y = [f64; 2];
-
-    fn f(&self, x: [f64; 2]) -> Self::Y {x}
-    fn g(&self, x: [f64; 2]) -> Self::Y {[x[0] + self.0[0], x[1] + self.0[1]]}
-    fn h(&self, x: [f64; 2], s: f64) -> Self::Y {[x[0] + s * self.0[0], x[1] + s * self.0[1]]}
-}
-
-impl Homotopy<[f64; 3]> for Translate<[f64; 3]> {
-    type Y = [f64; 3];
-
-    fn f(&self, x: [f64; 3]) -> Self::Y {x}
-    fn g(&self, x: [f64; 3]) -> Self::Y {
-        [
-            x[0] + self.0[0],
-            x[1] + self.0[1],
-            x[2] + self.0[2],
-        ]
-    }
-    fn h(&self, x: [f64; 3], s: f64) -> Self::Y {
-        [
-            x[0] + s * self.0[0],
-            x[1] + s * self.0[1],
-            x[2] + s * self.0[2],
-        ]
-    }
-}
-
-impl Homotopy<[f64; 4]> for Translate<[f64; 4]> {
-    type Y = [f64; 4];
+impl<X> Homotopy<X, X::Scalar> for Translate<X>
+    where X: VectorSpace
+{
+    type Y = X;
 
-    fn f(&self, x: [f64; 4]) -> Self::Y {x}
-    fn g(&self, x: [f64; 4]) -> Self::Y {
-        [
-            x[0] + self.0[0],
-            x[1] + self.0[1],
-            x[2] + self.0[2],
-            x[3] + self.0[3],
-        ]
-    }
-    fn h(&self, x: [f64; 4], s: f64) -> Self::Y {
-        [
-            x[0] + s * self.0[0],
-            x[1] + s * self.0[1],
-            x[2] + s * self.0[2],
-            x[3] + s * self.0[3],
-        ]
-    }
+    fn f(&self, x: X) -> Self::Y {x}
+    fn g(&self, x: X) -> Self::Y {x.add_vector(&self.0)}
+    fn h(&self, x: X, s: X::Scalar) -> Self::Y {x.add_vector(&self.0.scale(s))}
 }
 
-/// Create a sweep from two circles.
+/// Create a sweep from two curves, e.g. two circles.
 ///
-/// This is constructed by taking the diagonal of the square product of two circles.
+/// This is constructed by taking the diagonal of the square product of two curves.
 /// It can be thought of as making the circles rotate together, controlled by a single parameter.
 ///
 /// Then, a SMap adds a new dimension that interpolates along the sweep,
 /// making it possible to control both the rotation and position between the two circles.
-pub fn sweep(a: Circle<f64>, b: Circle<f64>) -> impl Homotopy<((), ()), [f64; 2], Y = [f64; 2]> {
-    Square::new(a, b).into_diagonal().into_smap(|(a, b), s| [
-            a[0] + (b[0] - a[0]) * s,
-            a[1] + (b[1] - a[1]) * s,
-        ])
+///
+/// For curves with the same input, see `Loft`.
+pub fn sweep<A, B, F>(a: A, b: B) -> impl Homotopy<((), ()), [F; 2], Y = A::Y>
+    where F: Float, A: Homotopy<(), F>, B: Homotopy<(), F, Y = A::Y>, A::Y: VectorSpace<Scalar = F>
+{
+    Square::new(a, b).into_diagonal().into_smap(|(a, b), s| a.lerp(&b, s))
 }
 
 #[cfg(test)]
@@ -983,21 +1205,74 @@ mod tests {
 
     #[test]
     fn check_id() {
-        assert!(check(&Id, 0.0 as f64));
-        assert!(check(&Id, 1.0 as f64));
-        assert!(check(&Id, true));
-        assert!(check(&Id, false));
+        // `Id` works with any scalar, so it is given explicitly.
+        assert!(check::<_, _, f64>(&Id, 0.0_f64));
+        assert!(check::<_, _, f64>(&Id, 1.0_f64));
+        assert!(check::<_, _, f64>(&Id, true));
+        assert!(check::<_, _, f32>(&Id, false));
     }
 
     #[test]
     fn check_dirac() {
-        assert!(checku(&Dirac));
+        assert!(checku::<_, _, f64>(&Dirac));
+        assert!(checku::<_, _, f32>(&Dirac));
+        assert_eq!(Dirac.hu(0.0f32), 1.0);
+        assert_eq!(Dirac.hu(0.5f32), 0.0);
     }
 
     #[test]
     fn check_dirac_from() {
         let ft = DiracFrom::new(|()| 1.0, |()| 0.0);
-        assert!(checku(&ft));
+        assert!(checku::<_, _, f64>(&ft));
+        assert_eq!((ft.hu(0.0f32), ft.hu(0.5f32)), (1.0, 0.0));
+    }
+
+    #[test]
+    fn check_continuity_dirac() {
+        let report = checku_continuity::<_, _, f64>(&Dirac);
+        assert!(!report.is_continuous());
+        assert_eq!(report.jumps.len(), 1);
+        assert_eq!(report.jumps[0].from, vec![0.0]);
+        assert!(report.jumps[0].to[0] < 1e-9);
+        assert_eq!(report.jumps[0].distance, 1.0);
+
+        let ft = DiracFrom::new(|()| 1.0, |()| 0.0);
+        assert!(!checku_continuity::<_, _, f64>(&ft).is_continuous());
+
+        let a: Compose<_, _, _, f64> =
+            Compose::new(Lerp(3.0, 10.0), DiracFrom::new(|x| x - 2.0, |x| x + 2.0));
+        let report = checku_continuity(&a);
+        assert_eq!(report.jumps.len(), 17);
+        assert!(report.jumps.iter().all(|jump| jump.from[1] == 0.0 && jump.distance == 4.0));
+    }
+
+    #[test]
+    fn check_continuity_lerp() {
+        let report = checku_continuity(&Lerp(0.0, 2.0));
+        assert!(report.is_continuous());
+        assert!((report.lipschitz - 2.0).abs() < 1e-9);
+        assert!((report.modulus - 0.125).abs() < 1e-9);
+
+        let a = Square::new(Circle {center: [0.0, 0.0], radius: 1.0}, Lerp(0.0, 1.0));
+        let report = checku_continuity(&a);
+        assert!(report.is_continuous());
+        assert!(report.lipschitz <= 2.0 * std::f64::consts::PI);
+    }
+
+    #[test]
+    fn check_continuity_steep() {
+        let report = checku_continuity(&Lerp(0.0, 1e8));
+        assert!(report.is_continuous());
+        assert!((report.lipschitz - 1e8).abs() < 1e-3);
+        assert!(checku_continuity(&Lerp(0.0f32, 1e8)).is_continuous());
+
+        let a = Circle {center: [1e6, -1e6], radius: 1e6};
+        assert!(checku_continuity(&a).is_continuous());
+
+        // Jumps are still found next to steep parts.
+        let b: Compose<_, _, _, f64> =
+            Compose::new(Lerp(0.0, 1e8), DiracFrom::new(|x| x, |x| x + 1.0));
+        assert_eq!(checku_continuity(&b).jumps.len(), 17);
     }
 
     #[test]
@@ -1006,6 +1281,33 @@ mod tests {
         assert!(checku(&lerp));
     }
 
+    #[test]
+    fn check_lerp_vectors() {
+        let a = Lerp([0.0, 1.0], [2.0, 3.0]);
+        assert!(checku(&a));
+        assert_eq!(a.hu(0.5), [1.0, 2.0]);
+
+        let b = Lerp((0.0, [1.0; 3]), (2.0, [3.0; 3]));
+        assert!(checku(&b));
+        assert_eq!(b.hu(0.5), (1.0, [2.0; 3]));
+
+        let c = Lerp(vec![0.0, 1.0], vec![2.0, 3.0]);
+        assert!(checku(&c));
+        assert_eq!(c.hu(0.5), vec![1.0, 2.0]);
+
+        let d = CubicBezier([0.0; 2], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]);
+        assert!(checku(&d));
+        assert_eq!(d.hu(1.0), [1.0, 0.0]);
+
+        let e = Compose::new(Lerp((0.0, 1.0), (1.0, 2.0)), Translate((10.0, 20.0)));
+        assert!(checku2(&e));
+        assert_eq!(e.hu([1.0, 1.0]), (11.0, 22.0));
+
+        let f = Circle {center: [[0.0; 2]; 2], radius: [1.0, 2.0]};
+        assert!(checku(&f));
+        assert_eq!(f.hu(0.5), [[-1.0, -2.0], [0.0; 2]]);
+    }
+
     #[test]
     fn check_quadratic_bezier() {
         let qb = QuadraticBezier(0.3, 0.7, 0.9);
@@ -1052,7 +1354,7 @@ mod tests {
         // Compose with a Dirac From that seperates the start of the line
         // from the rest of the line.
         let b = DiracFrom::new(|x| x - 2.0, |x| x + 2.0);
-        let c = Compose::new(a, b);
+        let c: Compose<_, _, _, f64> = Compose::new(a, b);
         assert!(checku2(&c));
 
         assert_eq!(c.hu([0.0, 0.0]), 1.0);
@@ -1080,8 +1382,8 @@ mod tests {
         assert!(checku2(&c));
         assert!(checku(&c.diagonal()));
         assert!(checku2(&c.as_vec()));
-        assert!(checku(&c.left_right(0.5)));
-        assert!(checku(&c.top_bottom(0.5)));
+        assert!(checku::<_, _, f64>(&c.left_right(0.5)));
+        assert!(checku::<_, _, f64>(&c.top_bottom(0.5)));
     }
 
     #[test]
@@ -1114,6 +1416,131 @@ mod tests {
         assert!(checku3(&c.past_future(0.5)));
     }
 
+    #[test]
+    fn check_higher_dimensions() {
+        let a = Cube::new(Lerp(0.0, 1.0), Lerp(0.0, 2.0), Lerp(0.0, 3.0));
+        let b = a.smap(|(x, y, z), s| x + y + z + 4.0 * s);
+        let c = b.smap(|x, s| x + 5.0 * s);
+        let d = Compose::new(c, Translate(6.0));
+        assert!(checkun::<_, _, [f64; 6]>(&d));
+        assert_eq!(d.hu([1.0; 6]), 21.0);
+        assert_eq!(d.hu([0.0, 0.0, 0.0, 0.0, 0.0, 1.0]), 6.0);
+        assert!(checku(&d.diagonal()));
+        assert!(checkun::<_, _, [f64; 5]>(&d.left_right(0.5)));
+        assert!(checkun::<_, _, [f64; 5]>(&d.past_future(0.5)));
+        assert_eq!(d.past().hu([1.0; 5]), 17.0);
+        assert_eq!(d.future().hu([0.0; 5]), 4.0);
+    }
+
+    #[test]
+    fn check_faces() {
+        let a = Cube::new(Lerp(0.0, 1.0), Lerp(0.0, 2.0), Lerp(0.0, 4.0));
+        let a = a.map(|(x, y, z)| x + y + z);
+        let sides = a.sides();
+        assert_eq!(sides.len(), 6);
+        for side in &sides {
+            assert!(checku2(side));
+        }
+        assert_eq!(sides[3].hu([0.5, 0.5]), a.bottom().hu([0.5, 0.5]));
+        assert_eq!(a.face::<2, true>().hu([0.0, 0.0]), 4.0);
+        assert_eq!(a.face::<2, false>().hu([1.0, 1.0]), 3.0);
+        assert_eq!(a.slice::<1>(0.5).hu([0.0, 0.0]), 1.0);
+        assert!(checku2(&a.slice::<2>(0.25)));
+    }
+
+    #[test]
+    fn check_coons() {
+        let a = Square::new(Lerp(0.0, 1.0), Lerp(0.0, 2.0));
+        let a = a.as_vec();
+        let b = Coons::try_new(a.left(), a.right(), a.top(), a.bottom(), [(); 2], 0.0).unwrap();
+        assert!(check2(&b, [(); 2]));
+        assert_eq!(b.h([(); 2], [0.5, 0.25]), [0.5, 0.5]);
+
+        let left = QuadraticBezier([0.0, 0.0], [-1.0, 0.5], [0.0, 1.0]);
+        let right = Lerp([1.0, 0.0], [1.0, 1.0]);
+        let top = Lerp([0.0, 0.0], [1.0, 0.0]);
+        let bottom = CubicBezier([0.0, 1.0], [0.3, 2.0], [0.6, 2.0], [1.0, 1.0]);
+        let c = Coons::try_new(left, right, top, bottom, (), 0.0).unwrap();
+        assert!(checku2(&c));
+        for &s in &[0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(c.left().hu(s), left.hu(s));
+            assert_eq!(c.right().hu(s), right.hu(s));
+            assert_eq!(c.top().hu(s), top.hu(s));
+            assert_eq!(c.bottom().hu(s), bottom.hu(s));
+        }
+        assert!(checku_continuity(&c).is_continuous());
+
+        let d = Coons::new(left, right, top, Lerp([0.0, 1.0], [1.0, 2.0]));
+        let err = d.check_corners((), 1e-9).unwrap_err();
+        assert_eq!(err.params, vec![1.0, 1.0]);
+        assert_eq!(err.first, [1.0, 1.0]);
+        assert_eq!(err.second, [1.0, 2.0]);
+    }
+
+    #[test]
+    fn check_fill() {
+        let a = Cube::new(Lerp(0.0, 1.0), Lerp(0.0, 2.0), Lerp(0.0, 4.0));
+        let a = a.map(|(x, y, z)| [x * y, y + z, x * z]);
+        let sides: Vec<_> = a.sides().into_iter().map(Some).collect();
+        let b = Fill::try_new(sides, ((), (), ()), 0.0).unwrap();
+        assert!(checku3(&b));
+        assert_eq!(b.front().hu([0.5, 0.5]), a.front().hu([0.5, 0.5]));
+        let s = [0.25, 0.5, 0.75];
+        assert!(b.hu(s).approx_eq(&a.hu(s), 1e-12));
+
+        // Open box, where the back side is computed.
+        let mut sides: Vec<_> = a.sides().into_iter().map(Some).collect();
+        sides[5] = None;
+        let c = Fill::try_new(sides, ((), (), ()), 0.0).unwrap();
+        assert!(checku_approx::<_, _, [f64; 3]>(&c, 1e-12));
+        assert!(c.back().hu([0.5, 0.25]).approx_eq(&a.back().hu([0.5, 0.25]), 1e-12));
+        assert!(c.hu(s).approx_eq(&a.hu(s), 1e-12));
+
+        // 2D is the same as a Coons patch.
+        let left = QuadraticBezier([0.0, 0.0], [-1.0, 0.5], [0.0, 1.0]);
+        let right = Lerp([1.0, 0.0], [1.0, 1.0]);
+        let top = Lerp([0.0, 0.0], [1.0, 0.0]);
+        let bottom = Lerp([0.0, 1.0], [1.0, 1.0]);
+        let d = Coons::new(left, right, top, bottom);
+        let sides = vec![Some(left), Some(right.into()), Some(top.into()), Some(bottom.into())];
+        let e: Fill<QuadraticBezier<[f64; 2]>, [f64; 2]> = Fill::try_new(sides, (), 0.0).unwrap();
+        assert!(checku2(&e));
+        assert!(e.hu([0.3, 0.6]).approx_eq(&d.hu([0.3, 0.6]), 1e-12));
+
+        // Sides of different shapes are boxed.
+        let sides: Vec<Option<Static<_, [f64; 2], f64>>> = vec![
+            Some(Static::try_new(left.into_box()).unwrap()),
+            Some(Static::try_new(right.into_box()).unwrap()),
+            Some(Static::try_new(top.into_box()).unwrap()),
+            Some(Static::try_new(bottom.into_box()).unwrap()),
+        ];
+        let e = Fill::<_, [f64; 2]>::try_new(sides, (), 0.0).unwrap();
+        assert!(e.hu([0.3, 0.6]).approx_eq(&d.hu([0.3, 0.6]), 1e-12));
+
+        // Faces of one shape are converted to `Side`.
+        let f = Square::new(Lerp(0.0, 1.0), Lerp(0.0, 2.0)).into_map(|(x, y)| [x, x * y]);
+        let sides: Vec<Option<Side<_>>> = vec![Some(f.left().into()), Some(f.right().into()),
+                                               Some(f.top().into()), Some(f.bottom().into())];
+        let g = Fill::<_, [f64; 2]>::try_new(sides, ((), ()), 0.0).unwrap();
+        assert_eq!(g.side(0, true).unwrap().axis, 0);
+        assert!(g.hu([0.3, 0.6]).approx_eq(&f.hu([0.3, 0.6]), 1e-12));
+
+        let sides = vec![Some(left), Some(right.into()), Some(top.into()), None];
+        let e: Fill<QuadraticBezier<[f64; 2]>, [f64; 2]> = Fill::try_new(sides, (), 0.0).unwrap();
+        assert_eq!(e.bottom().hu(0.5), [0.5, 1.0]);
+
+        let sides = vec![Some(left), Some(right.into()), Some(top.into()),
+                         Some(QuadraticBezier([0.0, 1.0], [0.5, 1.0], [1.0, 2.0]))];
+        let err = Fill::<_, [f64; 2]>::try_new(sides, (), 1e-9).err().unwrap();
+        assert_eq!(err, FillError::Mismatch {
+            first: (0, true),
+            second: (1, true),
+            mismatch: Mismatch {params: vec![1.0, 1.0], first: [1.0, 1.0], second: [1.0, 2.0]},
+        });
+        let err = Fill::<QuadraticBezier<[f64; 2]>, [f64; 2]>::try_new(vec![None; 4], (), 0.0);
+        assert_eq!(err.err().unwrap(), FillError::Missing {count: 4});
+    }
+
     #[test]
     fn check_invert() {
         let a = Lerp(2.0, 4.0);
@@ -1121,6 +1548,823 @@ mod tests {
         assert!(checku(&b));
     }
 
+    #[test]
+    fn check_concat() {
+        let a = Lerp(0.0, 2.0);
+        let b = Lerp(2.0, 3.0);
+        let c = a.concat(b);
+        assert!(checku(&c));
+        assert!(c.check_endpoints((), 0.0).is_ok());
+        assert_eq!(c.hu(0.25), 1.0);
+        assert_eq!(c.hu(0.5), 2.0);
+        assert_eq!(c.hu(0.75), 2.5);
+        assert!(checku_continuity(&c).is_continuous());
+
+        let d = c.concat(a.inverse());
+        assert!(checku(&d));
+        assert_eq!(d.hu(0.75), 1.0);
+
+        let err = Concat::try_new(a, Lerp(2.5, 3.0), (), 1e-9).err().unwrap();
+        assert_eq!(err, Mismatch {params: vec![0.5], first: 2.0, second: 2.5});
+
+        let e = ConcatMany::try_new(vec![a, b, Lerp(3.0, 0.0)], (), 0.0).unwrap();
+        assert!(checku(&e));
+        assert_eq!(e.hu(0.0), 0.0);
+        assert_eq!(e.hu(0.5), 2.5);
+        assert_eq!(e.hu(1.0), 0.0);
+        assert!(checku_continuity(&e).is_continuous());
+
+        let err = ConcatMany::try_new(vec![a, b, Lerp(2.0, 0.0)], (), 1e-9).err().unwrap();
+        assert_eq!(err.first, 3.0);
+        assert_eq!(err.second, 2.0);
+        assert!((err.params[0] - 2.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn check_groupoid_laws() {
+        let p = Lerp(0.0, 2.0);
+        let q = QuadraticBezier(2.0, 5.0, 3.0);
+        let r = Lerp(3.0, -1.0);
+        let samples = [0.0, 0.1, 0.25, 0.3, 0.5, 0.6, 0.75, 0.9, 1.0];
+
+        let a = Assoc(p, q, r);
+        assert!(checku2(&a));
+        let (left, right) = (p.concat(q).into_concat(r), p.into_concat(q.concat(r)));
+        for &s in &samples {
+            assert_eq!(a.left().hu(s), left.hu(s));
+            assert_eq!(a.right().hu(s), right.hu(s));
+        }
+        assert!(checku_continuity(&a).is_continuous());
+
+        let b = LeftUnit(q);
+        assert!(checku2(&b));
+        let left = Refl(q).into_concat(q);
+        for &s in &samples {
+            assert_eq!(b.left().hu(s), left.hu(s));
+            assert_eq!(b.right().hu(s), q.hu(s));
+        }
+        assert!(checku_continuity(&b).is_continuous());
+
+        let c = RightInverse(q);
+        assert!(checku2(&c));
+        let left = q.concat(q.inverse());
+        for &s in &samples {
+            assert_eq!(c.left().hu(s), left.hu(s));
+            assert_eq!(c.right().hu(s), Refl(q).hu(s));
+        }
+        assert!(checku_continuity(&c).is_continuous());
+    }
+
+    #[test]
+    fn check_reparam() {
+        let a = CubicBezier(0.0, 2.0, 3.0, 1.0);
+        let ease = QuadraticBezier(0.0, 0.0, 1.0);
+        let b = a.reparam(ease);
+        assert!(checku(&b));
+        assert_eq!(b.hu(0.5), a.hu(0.25));
+        assert_eq!((b.f(()), b.g(())), (a.f(()), a.g(())));
+        // The identity map leaves the path unchanged.
+        let identity = a.reparam(Lerp(0.0, 1.0));
+        for &s in &[0.0, 0.3, 0.5, 1.0] {
+            assert_eq!(identity.hu(s), a.hu(s));
+        }
+
+        let c = Circle {center: [0.0, 0.0], radius: 2.0}.into_reparam(ease);
+        assert!(checku(&c));
+        assert_eq!(c.hu(0.5), c.0.hu(0.25));
+
+        assert!(Reparam::try_new(a, ease, 0.0).is_ok());
+        let err = Reparam::try_new(a, Lerp(0.0, 0.5), 1e-6).err().unwrap();
+        assert_eq!(err.params, vec![1.0]);
+    }
+
+    #[test]
+    fn check_reparam_n() {
+        use easing::*;
+
+        let a = Square::new(Lerp(0.0, 4.0), QuadraticBezier(0.0, 3.0, 1.0));
+        let b = ReparamN(a, [QuadraticBezier(0.0, 0.0, 1.0), QuadraticBezier(0.0, 1.0, 1.0)]);
+        assert!(checku2(&b));
+        assert_eq!(b.hu([0.5, 0.5]), a.hu([0.25, 0.75]));
+        // The maps fix the ends of each axis, so the sides are reparametrized in place.
+        for &s in &[0.0, 0.5, 1.0] {
+            assert_eq!(b.left().hu(s).0, 0.0);
+            assert_eq!(b.right().hu(s).0, 4.0);
+            assert_eq!(b.top().hu(s).1, 0.0);
+            assert_eq!(b.bottom().hu(s).1, 1.0);
+        }
+
+        let err = ReparamN::try_new(a, [Lerp(0.0, 1.0), Lerp(0.5, 1.0)], 1e-6).err().unwrap();
+        assert_eq!(err.params, vec![0.0, 0.0]);
+        let err = ReparamN::try_new(a, [Lerp(0.0, 1.0), Lerp(0.0, 0.5)], 1e-6).err().unwrap();
+        assert_eq!(err.params, vec![0.0, 1.0]);
+
+        // Each axis can have a different type of map.
+        let c = ReparamN(a, (Quad(Ease::In), Sine(Ease::Out)));
+        assert!(checku2(&c));
+        assert_eq!(c.hu([0.5, 0.0]), a.hu([0.25, 0.0]));
+        assert!(c.hu([0.0, 0.5]).approx_eq(&a.hu([0.0, 0.5f64.sqrt()]), 1e-12));
+        let err = ReparamN::try_new(a, (Lerp(0.0, 1.0), QuadraticBezier(0.0, 1.0, 0.5)), 1e-6);
+        assert_eq!(err.err().unwrap().params, vec![0.0, 1.0]);
+        let d = ReparamN(Cube::new(Lerp(0.0, 1.0), Lerp(0.0, 1.0), Lerp(0.0, 1.0)),
+                         (Lerp(0.0, 1.0), Quad(Ease::In), Cubic(Ease::In)));
+        assert_eq!(d.hu([0.5, 0.5, 0.5]), (0.5, 0.25, 0.125));
+    }
+
+    #[test]
+    fn check_easing() {
+        use easing::*;
+
+        fn check_ease<E: Easing + Homotopy<(), Y = f64> + Copy>(e: E, continuous: bool) {
+            assert!(checku(&e));
+            assert!(checku(&CubicBezier(0.0, 2.0, 3.0, 1.0).into_reparam(e)));
+            assert!(checku(&Circle {center: [0.0, 0.0], radius: 1.0}.into_reparam(e)));
+            assert!(Reparam::try_new(Lerp(0.0, 1.0), e, 0.0).is_ok());
+            assert_eq!(checku_continuity(&e).is_continuous(), continuous);
+            let overshoots = (0..=100).map(|i| e.hu(i as f64 / 100.0))
+                .any(|s| !(0.0..=1.0).contains(&s));
+            assert_eq!(overshoots, E::OVERSHOOTS);
+            // The derivative is carried through, instead of being dropped by rounding.
+            if continuous {
+                for &s in &[0.1, 0.3, 0.6, 0.9] {
+                    let du = e.ease(Dual::var(s)).du;
+                    let diff = (e.hu(s + 1e-6) - e.hu(s - 1e-6)) / 2e-6;
+                    assert!((du - diff).abs() < 1e-4 * (1.0 + diff.abs()), "{:?}", (s, du, diff));
+                }
+            }
+        }
+
+        for &ease in &[Ease::In, Ease::Out, Ease::InOut] {
+            check_ease(Quad(ease), true);
+            check_ease(Cubic(ease), true);
+            check_ease(Quartic(ease), true);
+            check_ease(Sine(ease), true);
+            check_ease(Expo(ease), true);
+            check_ease(Back(ease), true);
+            check_ease(Elastic(ease), true);
+            check_ease(Bounce(ease), true);
+        }
+        check_ease(Steps(4), false);
+        assert_eq!(Steps(4).hu(0.3), 0.25);
+        assert_eq!(Quad(Ease::InOut).hu(0.25), 0.125);
+        assert_eq!(Quad(Ease::Out).hu(0.5), 0.75);
+        assert_eq!(Quad(Ease::In).h((), Dual::var(0.5)), Dual::new(0.25, 1.0));
+        assert_eq!(Steps(4).h((), Dual::var(0.3)), Dual::constant(0.25));
+    }
+
+    #[test]
+    fn check_along() {
+        let a = Square::new(Lerp(0.0, 2.0), Circle {center: [0.0, 0.0], radius: 1.0});
+        let diagonal = a.along(Lerp([0.0, 0.0], [1.0, 1.0]));
+        assert!(checku(&diagonal));
+        let left = a.along(Lerp([0.0, 0.0], [0.0, 1.0]));
+        for i in 0..=8 {
+            let s = i as f64 / 8.0;
+            assert_eq!(diagonal.hu(s), a.diagonal().hu(s));
+            assert_eq!(left.hu(s), a.left().hu(s));
+        }
+
+        // Walk along a spiral inside the unit square.
+        let spiral = Circle {center: [0.5, 0.5], radius: 0.25}.into_smap(|[x, y], s| {
+            [0.5 + (x - 0.5) * (1.0 - s), 0.5 + (y - 0.5) * (1.0 - s)]
+        }).into_diagonal();
+        let b = a.along(spiral);
+        assert!(checku(&b));
+        assert_eq!(b.hu(0.0), a.hu([0.75, 0.5]));
+        assert_eq!(b.hu(1.0), a.hu([0.5, 0.5]));
+
+        // A path that stays at one point gives a constant path.
+        let c = a.along(Lerp([0.25, 0.75], [0.25, 0.75]));
+        assert_eq!((c.hu(0.0), c.hu(0.5), c.hu(1.0)), ((0.5, [0.0, -1.0]), (0.5, [0.0, -1.0]),
+                                                     (0.5, [0.0, -1.0])));
+
+        // A path that goes back and forth ends where it started.
+        let d = a.along(Lerp([0.0, 0.25], [1.0, 0.25]).into_concat(Lerp([1.0, 0.25], [0.0, 0.25])));
+        assert!(checku(&d));
+        assert_eq!(d.hu(0.0), d.hu(1.0));
+        assert_eq!(d.hu(0.5), a.hu([1.0, 0.25]));
+    }
+
+    #[test]
+    fn check_restrict() {
+        let a = CubicBezier(0.0, 2.0, 3.0, 1.0).into_restrict(0.25, 0.75);
+        assert!(checku(&a));
+        assert_eq!(a.hu(0.0), a.shape().hu(0.25));
+        assert_eq!(a.hu(1.0), a.shape().hu(0.75));
+
+        // The middle third of a square.
+        let b = Square::new(Lerp(0.0, 3.0), QuadraticBezier(0.0, 1.0, 3.0));
+        let c = b.restrict([1.0 / 3.0; 2], [2.0 / 3.0; 2]);
+        assert!(checku2(&c));
+        assert_eq!(c.hu([0.0, 0.0]), b.hu([1.0 / 3.0; 2]));
+        assert_eq!(c.hu([1.0, 1.0]), b.hu([2.0 / 3.0; 2]));
+        assert_eq!(c.left().hu(0.5), b.hu([1.0 / 3.0, 0.5]));
+        // The corners are exact, so restrictions to adjacent boxes share a side.
+        let d = b.restrict([2.0 / 3.0, 1.0 / 3.0], [1.0, 2.0 / 3.0]);
+        for &s in &[0.0, 0.3, 1.0] {
+            assert_eq!(c.right().hu(s), d.left().hu(s));
+        }
+
+        // A corner patch of a cube, with an axis flipped by reflecting.
+        let d = Cube::new(Lerp(0.0, 2.0), Circle {center: [0.0, 0.0], radius: 1.0},
+                          QuadraticBezier(1.0, 4.0, 2.0));
+        let e = d.restrict([0.5, 0.0, 0.5], [1.0, 0.5, 1.0])
+            .into_reflect([false, false, true]);
+        assert!(checku3(&e));
+        assert_eq!(e.hu([0.0; 3]), d.hu([0.5, 0.0, 1.0]));
+        assert_eq!(e.hu([1.0; 3]), d.hu([1.0, 0.5, 0.5]));
+        assert_eq!(e.front().hu([0.0, 0.0]), d.hu([0.5, 0.0, 1.0]));
+
+        // A box with zero width on some axis is allowed.
+        let f = b.restrict([0.5, 0.0], [0.5, 1.0]);
+        assert_eq!(f.hu([0.3, 0.7]), b.hu([0.5, 0.7]));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a sub-box of the unit cube")]
+    fn check_restrict_reversed() {
+        let _ = Restrict::new(Lerp(0.0, 1.0), 0.75, 0.25);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a sub-box of the unit cube")]
+    fn check_restrict_outside() {
+        let _ = Square::new(Lerp(0.0, 1.0), Lerp(0.0, 1.0)).into_restrict([0.0, 0.5], [1.0, 1.5]);
+    }
+
+    #[test]
+    fn check_permute() {
+        let a = Square::new(Lerp(0.0, 1.0), QuadraticBezier(2.0, 5.0, 3.0));
+        let b = a.permute([1, 0]);
+        assert!(checku2(&b));
+        assert_eq!(b.hu([0.25, 0.5]), a.hu([0.5, 0.25]));
+        assert_eq!(b.left().hu(0.5), a.top().hu(0.5));
+
+        // A 3-cycle moves every axis, and applying it three times gives the identity.
+        let c = Cube::new(Lerp(0.0, 1.0), QuadraticBezier(0.0, 2.0, 1.0), Lerp(5.0, 3.0));
+        let d = c.permute([2, 0, 1]);
+        assert!(checku3(&d));
+        assert_eq!(d.hu([0.1, 0.2, 0.3]), c.hu([0.2, 0.3, 0.1]));
+        assert_eq!(d.axes(), [2, 0, 1]);
+        assert_eq!(d.front().hu([0.4, 0.7]), c.hu([0.7, 0.0, 0.4]));
+        let e = d.permute([2, 0, 1]).into_permute([2, 0, 1]);
+        assert_eq!(e.hu([0.1, 0.2, 0.3]), c.hu([0.1, 0.2, 0.3]));
+        // A transposition is its own inverse.
+        let f = c.permute([0, 2, 1]).into_permute([0, 2, 1]);
+        assert_eq!(f.hu([0.1, 0.2, 0.3]), c.hu([0.1, 0.2, 0.3]));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a permutation, found [1, 1]")]
+    fn check_permute_repeated() {
+        let a = Square::new(Lerp(0.0, 1.0), Lerp(2.0, 3.0));
+        let _ = a.permute([1, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a permutation, found [0, 2]")]
+    fn check_permute_out_of_range() {
+        let a = Square::new(Lerp(0.0, 1.0), Lerp(2.0, 3.0));
+        let _ = a.permute([0, 2]);
+    }
+
+    #[test]
+    fn check_reflect() {
+        let a = Cube::new(Lerp(0.0, 1.0), QuadraticBezier(0.0, 2.0, 1.0), Lerp(5.0, 3.0));
+        let b = a.reflect([false, true, false]);
+        assert!(checku3(&b));
+        assert_eq!(b.hu([0.0; 3]), a.hu([0.0, 1.0, 0.0]));
+        assert_eq!(b.hu([1.0; 3]), a.hu([1.0, 0.0, 1.0]));
+        assert_eq!(b.hu([0.25, 0.25, 0.5]), a.hu([0.25, 0.75, 0.5]));
+        // The reflected axis swaps opposite sides, while the others are kept.
+        assert_eq!(b.top().hu([0.3, 0.6]), a.bottom().hu([0.3, 0.6]));
+        assert_eq!(b.left().hu([0.25, 0.5]), a.left().hu([0.75, 0.5]));
+
+        // Reflecting no axes is the identity, and reflecting twice undoes it.
+        let c = a.reflect([false; 3]);
+        let d = b.reflect([false, true, false]);
+        for &s in &[[0.0; 3], [0.25, 0.5, 0.75], [1.0; 3]] {
+            assert_eq!(c.hu(s), a.hu(s));
+            assert_eq!(d.hu(s), a.hu(s));
+        }
+    }
+
+    #[test]
+    fn check_glue() {
+        // Two strips that meet along `x = 1.0`, where the second one is twice as wide.
+        let a = Square::new(Lerp(0.0, 1.0), QuadraticBezier(2.0, 4.0, 3.0));
+        let b = Square::new(Lerp(1.0, 3.0), QuadraticBezier(2.0, 4.0, 3.0));
+        let c = Glue::try_new(a, b, 0, ((), ()), 0.0).unwrap();
+        assert!(checku2(&c));
+        assert_eq!(c.axis(), 0);
+        // Each half is stretched to fill the whole shape.
+        assert_eq!(c.hu([0.25, 0.5]), a.hu([0.5, 0.5]));
+        assert_eq!(c.hu([0.75, 0.5]), b.hu([0.5, 0.5]));
+        // At the seam, both halves agree.
+        for &s in &[0.0, 0.3, 1.0] {
+            assert_eq!(c.hu([0.5, s]), a.right().hu(s));
+            assert_eq!(c.hu([0.5, s]), b.left().hu(s));
+            assert_eq!(c.left_right(0.5).hu(s), a.right().hu(s));
+        }
+        // The sides along the glued axis are glued too.
+        assert_eq!(c.top().hu(0.5), (1.0, 2.0));
+        assert_eq!(c.bottom().hu(0.75), b.bottom().hu(0.5));
+
+        // Gluing the strips along the other axis does not agree.
+        let err = Glue::try_new(a, b, 1, ((), ()), 1e-12).err().unwrap();
+        assert_eq!(err.params, vec![0.0, 0.5]);
+        assert_eq!((err.first, err.second), ((0.0, 3.0), (1.0, 2.0)));
+        // Within tolerance, the seam can have a small gap.
+        let d = Square::new(Lerp(1.0 + 1e-9, 3.0), QuadraticBezier(2.0, 4.0, 3.0));
+        assert!(Glue::try_new(a, d, 0, ((), ()), 0.0).is_err());
+        assert!(Glue::try_new(a, d, 0, ((), ()), 1e-6).is_ok());
+
+        // Two boxes stacked along the last axis.
+        let e = Cube::new(Lerp(0.0, 1.0), QuadraticBezier(0.0, 2.0, 1.0), Lerp(-1.0, 0.0));
+        let f = Cube::new(Lerp(0.0, 1.0), QuadraticBezier(0.0, 2.0, 1.0), Lerp(0.0, 2.0));
+        let g = Glue::try_new(e, f, 2, ((), (), ()), 0.0).unwrap();
+        assert!(checku3(&g));
+        assert_eq!(g.hu([0.5, 0.5, 0.25]), (0.5, 1.25, -0.5));
+        assert_eq!(g.hu([0.5, 0.5, 0.5]), (0.5, 1.25, 0.0));
+        assert_eq!(g.hu([0.5, 0.5, 0.75]), (0.5, 1.25, 1.0));
+        assert_eq!(g.front().hu([0.25, 0.75]), e.front().hu([0.25, 0.75]));
+        assert_eq!(g.back().hu([0.25, 0.75]), f.back().hu([0.25, 0.75]));
+
+        let err = Glue::try_new(e, f, 1, ((), (), ()), 1e-6).err().unwrap();
+        assert_eq!(err.params, vec![0.0, 0.5, 0.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected an axis less than 2, found 2")]
+    fn check_glue_invalid() {
+        let a = Square::new(Lerp(0.0, 1.0), Lerp(2.0, 3.0));
+        let _ = Glue::new(a, a, 2);
+    }
+
+    #[test]
+    fn check_contramap() {
+        let a = Square::new(Lerp(0.0, 1.0), Circle {center: [0.0, 0.0], radius: 1.0});
+        let b = a.contramap(|()| ((), ()));
+        assert!(checku2(&b));
+        assert_eq!(b.hu([0.5, 0.25]), (0.5, [0.0, 1.0]));
+
+        let c = Translate(2.0).into_contramap(|x: [f64; 2]| x[0] + x[1]);
+        assert!(check(&c, [1.0, 2.0]));
+        assert_eq!(c.h([1.0, 2.0], 0.5), 4.0);
+    }
+
+    #[test]
+    fn check_zip() {
+        let a = Lerp(0.0, 1.0).into_zip(Circle {center: [0.0, 0.0], radius: 1.0});
+        assert!(checku(&a));
+        assert_eq!(a.hu(0.25), (0.25, [0.0, 1.0]));
+
+        // Zipping with a transposed copy swaps the sides in the second component.
+        let b = Square::new(Lerp(0.0, 1.0), QuadraticBezier(2.0, 4.0, 3.0));
+        let c = b.zip(b.permute([1, 0]));
+        assert!(checku2(&c));
+        assert_eq!(c.hu([0.0, 1.0]), ((0.0, 3.0), (1.0, 2.0)));
+        assert_eq!(c.left().hu(0.5), (b.left().hu(0.5), b.top().hu(0.5)));
+        assert_eq!(c.diagonal().hu(0.5), (b.hu([0.5, 0.5]), b.hu([0.5, 0.5])));
+
+        // The input is shared by both homotopies.
+        let d = Translate(1.0).into_zip(Translate(2.0).inverse());
+        assert!(check(&d, 3.0));
+        assert_eq!(d.h(3.0, 0.25), (3.25, 4.5));
+    }
+
+    #[test]
+    fn check_ops() {
+        // A circle moving to the right.
+        let a = Circle {center: [0.0, 0.0], radius: 1.0} + Lerp([0.0, 0.0], [2.0, 0.0]);
+        assert!(checku(&a));
+        assert_eq!(a.hu(0.25), [0.5, 1.0]);
+
+        // Plain scalars are constants.
+        let b = Lerp(1.0, 3.0) - QuadraticBezier(0.0, 1.0, 0.0) * 2.0;
+        assert!(checku(&b));
+        assert_eq!(b.hu(0.5), 1.0);
+        assert_eq!((b.hu(0.0), b.hu(1.0)), (1.0, 3.0));
+
+        // Vectors are scaled by scalar homotopies.
+        let c = Lerp([1.0, 2.0], [3.0, 4.0]) * Lerp(1.0, 0.0);
+        assert!(checku(&c));
+        assert_eq!(c.hu(0.5), [1.0, 1.5]);
+        assert_eq!(c.hu(1.0), [0.0, 0.0]);
+
+        // The endpoints of a sum are the sums of the endpoints.
+        let d = Square::new(Lerp(0.0, 1.0), Lerp(2.0, 4.0)).into_map(|(a, b)| a * b);
+        let e = d.ops() + d.permute([1, 0]) * 2.0;
+        assert!(checku2(&e));
+        assert_eq!(e.hu([0.0, 1.0]), 4.0);
+        assert_eq!((e.f(((), ())), e.g(((), ()))), (0.0, 12.0));
+
+        // Results can be chained, and a scale of zero gives a constant.
+        let f = (Lerp(1.0, 3.0) + Lerp(0.0, 1.0) - Lerp(1.0, 1.0)) * 0.0;
+        assert_eq!((f.hu(0.0), f.hu(0.5), f.hu(1.0)), (0.0, 0.0, 0.0));
+
+        // Any value can be a constant, e.g. a dual number.
+        let g = Lerp(Dual::constant(1.0), Dual::constant(3.0)) * Dual::var(2.0);
+        assert_eq!(g.h((), Dual::constant(0.5)), Dual::new(4.0, 2.0));
+
+        // References and homotopies from other crates are wrapped.
+        let h = Lerp(0.0, 1.0);
+        let i = h.ops() * h;
+        assert_eq!(i.hu(0.5), 0.25);
+    }
+
+    #[test]
+    fn check_sweep() {
+        let a = Circle {center: [0.0, 0.0], radius: 1.0};
+        let b = Circle {center: [0.0, 0.0], radius: 2.0};
+        let c = sweep(a, b);
+        assert!(checku2(&c));
+        assert_eq!(c.hu([0.25, 0.5]), [0.0, 1.5]);
+
+        let d = sweep(Lerp([0.0, 0.0, 0.0], [1.0, 0.0, 0.0]), CubicBezier::from_quadratic(
+            [0.0, 1.0, 1.0], [0.5, 2.0, 1.0], [1.0, 1.0, 1.0]));
+        assert!(checku2(&d));
+        assert_eq!(d.hu([0.0, 0.5]), [0.0, 0.5, 0.5]);
+    }
+
+    #[test]
+    fn check_loft() {
+        let a = loft(Circle {center: [0.0, 0.0], radius: 1.0}, Lerp([1.0, 0.0], [1.0, 0.0]));
+        assert!(checku2(&a));
+        assert_eq!(a.hu([0.5, 0.5]), [0.0, 0.0]);
+        assert_eq!(a.top().hu(0.25), [0.0, 1.0]);
+        assert!(checku_continuity(&a).is_continuous());
+
+        // The curves share any input.
+        let c = loft(Translate(1.0), Translate(3.0));
+        assert!(check2(&c, 1.0));
+        assert_eq!(c.h(1.0, [0.5, 0.5]), 2.0);
+        assert_eq!(c.bottom().h(1.0, 0.5), 2.5);
+
+        let sections = vec![
+            Lerp([0.0, 0.0, 0.0], [1.0, 0.0, 0.0]),
+            Lerp([0.0, 1.0, 1.0], [1.0, 1.0, 1.0]),
+            Lerp([0.0, 2.0, 0.0], [1.0, 2.0, 0.0]),
+            Lerp([0.0, 3.0, 2.0], [1.0, 3.0, 2.0]),
+        ];
+        for &blend in &[Blend::Linear, Blend::Bezier, Blend::CatmullRom] {
+            let b = LoftMany::new(sections.clone(), blend);
+            assert!(checku2(&b));
+            assert_eq!(b.hu([0.5, 0.0]), [0.5, 0.0, 0.0]);
+            assert_eq!(b.hu([0.5, 1.0]), [0.5, 3.0, 2.0]);
+            assert!(checku_continuity(&b).is_continuous());
+        }
+
+        // Interpolating blends pass through every section.
+        for &blend in &[Blend::Linear, Blend::CatmullRom] {
+            let b = LoftMany::new(sections.clone(), blend);
+            assert_eq!(b.hu([0.5, 1.0 / 3.0]), [0.5, 1.0, 1.0]);
+            assert_eq!(b.hu([0.5, 2.0 / 3.0]), [0.5, 2.0, 0.0]);
+        }
+        let b = LoftMany::new(sections.clone(), Blend::Bezier);
+        assert_eq!(b.hu([0.0, 0.5]), [0.0, 1.5, 0.625]);
+    }
+
+    #[test]
+    fn check_shape() {
+        fn dim<H: Shape<X, S>, X, S: Param>(_: &H) -> usize {H::DIM}
+
+        let a = Circle {center: [0.0, 0.0], radius: 1.0};
+        assert_eq!(dim(&a), 1);
+        assert_eq!(a.endpoints((), 0.0), Endpoints::Closed);
+        assert_eq!(Lerp(0.0, 1.0).endpoints((), 0.0), Endpoints::Open);
+
+        let b = Cube::new(Lerp(0.0, 1.0), a, Lerp(2.0, 2.0));
+        assert_eq!(dim(&b), 3);
+        assert_eq!(b.diagonal().dim(), 1);
+        assert_eq!(b.endpoints(((), (), ()), 0.0), Endpoints::Open);
+        let c = Cube::new(Lerp(1.0, 1.0), a, Lerp(2.0, 2.0));
+        assert_eq!(c.endpoints(((), (), ()), 0.0), Endpoints::Closed);
+
+        // Works for any dimension.
+        assert!(checkun(&a));
+        assert!(checkun(&b));
+        assert!(checkun(&b.diagonal()));
+    }
+
+    #[test]
+    fn check_sample_grid() {
+        let a = sampleu_grid(&Lerp(0.0, 2.0), 4);
+        assert_eq!(a, vec![(0.0, 0.0), (0.25, 0.5), (0.5, 1.0), (0.75, 1.5), (1.0, 2.0)]);
+
+        let b = Square::new(Lerp(0.0, 1.0), Lerp(2.0, 3.0));
+        let c = sampleu_grid(&b, 2);
+        assert_eq!(c.len(), 9);
+        assert_eq!(c[0], ([0.0, 0.0], (0.0, 2.0)));
+        assert_eq!(c[1], ([0.5, 0.0], (0.5, 2.0)));
+        assert_eq!(c[3], ([0.0, 0.5], (0.0, 2.5)));
+        assert_eq!(c[8], ([1.0, 1.0], (1.0, 3.0)));
+
+        let d = Cube::new(Lerp(0.0, 1.0), Lerp(2.0, 3.0), Lerp(4.0, 5.0));
+        assert_eq!(sampleu_grid(&d, 3).len(), 64);
+    }
+
+    #[test]
+    fn check_dyn() {
+        let a = Square::new(Lerp(0.0, 1.0), QuadraticBezier(2.0, 4.0, 3.0))
+            .into_map(|(a, b)| a + b).into_contramap(|()| ((), ()));
+        let b = Cube::new(Lerp(0.0, 1.0), Lerp(2.0, 3.0), Lerp(4.0, 5.0));
+        let shapes: Vec<Box<dyn DynHomotopy<(), f64>>> = vec![
+            Lerp(0.0, 2.0).into_box(),
+            a.into_box(),
+            b.into_map(|(a, b, c)| a + b + c).into_contramap(|()| ((), (), ())).into_box(),
+        ];
+        assert_eq!(shapes.iter().map(|s| s.dim()).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(shapes[0].h((), &[0.5]), 1.0);
+        assert_eq!(shapes[1].h((), &[0.5, 0.5]), a.hu([0.5, 0.5]));
+        assert_eq!(shapes[2].h((), &[0.5, 0.5, 0.5]), 7.5);
+        assert_eq!((shapes[2].f(()), shapes[2].g(())), (6.0, 9.0));
+
+        // Convert back to use static combinators.
+        let c = Static::<_, _, [f64; 2]>::try_new(a.into_arc()).unwrap();
+        assert!(checku2(&c));
+        assert_eq!(c.left().hu(0.5), 3.25);
+        assert_eq!(c.diagonal().hu(0.5), a.hu([0.5, 0.5]));
+        assert_eq!(c.map(|y| y * 2.0).hu([1.0, 1.0]), 8.0);
+
+        let d = Static::<_, _, f64>::try_new(&shapes[0]).unwrap();
+        assert!(checku(&d));
+        let err = Static::<_, f64, [f64; 3]>::try_new::<()>(&shapes[1]).err().unwrap();
+        assert_eq!(err, DimError {expected: 3, found: 2});
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_dyn_wrong_dim() {
+        let a: Box<dyn DynHomotopy<(), f64>> = Square::new(Lerp(0.0, 1.0), Lerp(2.0, 3.0))
+            .into_map(|(a, b)| a + b).into_contramap(|()| ((), ())).into_box();
+        let _ = a.h((), &[0.5]);
+    }
+
+    #[test]
+    fn check_f32() {
+        let a = Lerp([0.0f32, 1.0], [1.0, 2.0]);
+        assert!(checkun(&a));
+        assert_eq!(a.hu(0.5f32), [0.5, 1.5]);
+
+        let b = Circle {center: [0.0f32; 2], radius: 1.0};
+        assert!(checkun(&b));
+        assert_eq!(b.hu(0.25f32), [0.0, 1.0]);
+        assert!((b.hu(0.125f32)[0] - 0.5f32.sqrt()).abs() < 1e-6);
+
+        let c = Square::new(Lerp(0.0f32, 1.0), Lerp(0.0f32, 2.0));
+        assert!(checkun(&c));
+        assert_eq!(c.hu([0.5f32, 0.25]), (0.5, 0.5));
+        assert_eq!(c.left().hu(0.5f32), (0.0, 1.0));
+        assert_eq!(c.left_right(0.5f32).hu(1.0f32), (0.5, 2.0));
+        assert_eq!(c.diagonal().hu(0.5f32), (0.5, 1.0));
+
+        let d = Coons::new(a, a, Lerp([0.0f32, 1.0], [0.0, 1.0]), Lerp([1.0f32, 2.0], [1.0, 2.0]));
+        assert!(checkun(&d));
+        assert_eq!(d.hu([0.5f32, 0.5]), [0.5, 1.5]);
+
+        let e = sweep(b, Circle {center: [0.0f32; 2], radius: 2.0});
+        assert_eq!(e.hu([0.25f32, 0.5]), [0.0, 1.5]);
+        let f = a.concat(Lerp([1.0f32, 2.0], [2.0, 2.0]));
+        let f = f.into_reparam(easing::Quad(easing::Ease::In));
+        assert_eq!(f.hu(0.5f32), [0.5, 1.5]);
+        assert_eq!((a * 2.0f32).hu(1.0f32), [2.0, 4.0]);
+
+        assert!(check(&a, ()));
+        assert!(check2(&c, ((), ())));
+        assert!(checku3(&Cube::new(a, b, Lerp(0.0f32, 1.0))));
+        let g = Lerp([0.0f32, 0.0], [3.0, 4.0]).into_reparam(QuadraticBezier(0.0, 0.0, 1.0));
+        let g = ArcLength::new(g, (), 1e-6);
+        assert!(checku(&g));
+        assert!((g.length() - 5.0).abs() < 1e-6);
+        assert!(g.hu(0.5f32).approx_eq(&[1.5, 2.0], 1e-5));
+        assert!((arc_lengthu(&b, 1e-6) - 2.0 * std::f64::consts::PI).abs() < 1e-5);
+    }
+
+    #[test]
+    fn check_derivative() {
+        use std::f64::consts::PI;
+
+        assert_eq!(Lerp(1.0, 3.0).derivative((), 0.25), 2.0);
+        assert_eq!(QuadraticBezier(0.0, 1.0, 1.0).derivative((), 0.5), 1.0);
+
+        let a = Circle {center: [0.0; 2], radius: 2.0};
+        assert!(a.derivative((), 0.25).approx_eq(&[-4.0 * PI, 0.0], 1e-9));
+        // The derivative is kept where the value is special-cased.
+        assert!(a.derivative((), 1.0).approx_eq(&[0.0, 4.0 * PI], 1e-9));
+        assert!(a.inverse().derivative((), 0.0).approx_eq(&[0.0, -4.0 * PI], 1e-9));
+
+        let b = Square::new(Lerp(0.0, 2.0), Lerp(1.0, 4.0));
+        assert_eq!(b.jacobian(((), ()), [0.5, 0.5]), vec![(2.0, 0.0), (0.0, 3.0)]);
+        assert_eq!(b.left().derivative(((), ()), 0.5), (0.0, 3.0));
+        assert_eq!(b.top_bottom(0.5).derivative(((), ()), 0.5), (2.0, 0.0));
+        assert_eq!(b.diagonal().derivative(((), ()), 0.5), (2.0, 3.0));
+
+        let c = Compose::new(Lerp(1.0, 2.0), Translate(3.0));
+        assert_eq!(c.jacobian((), [0.5, 0.5]), vec![1.0, 3.0]);
+
+        let d = Lerp(0.0, 2.0).into_map(|y| y * y);
+        assert!((d.derivative((), 0.5) - 4.0).abs() < 1e-6);
+        let e = Compose::new(Lerp(0.0, 2.0), Translate(1.0).into_map(|y: f64| y * y));
+        assert!(e.partial((), [0.5, 0.0], 0).approx_eq(&4.0, 1e-6));
+    }
+
+    #[test]
+    fn check_arc_length() {
+        use std::f64::consts::PI;
+
+        let a = Circle {center: [0.0; 2], radius: 2.0};
+        assert!((arc_lengthu(&a, 1e-9) - 4.0 * PI).abs() < 1e-6);
+        assert!((arc_lengthu(&Lerp([0.0, 0.0], [3.0, 4.0]), 1e-9) - 5.0).abs() < 1e-9);
+
+        // Slow at the start, since it is quadratic in the parameter.
+        let b = CubicBezier([0.0, 0.0], [0.0, 0.0], [0.0, 0.0], [1.0, 0.0]);
+        assert_eq!(b.hu(0.25), [0.0625, 0.0]);
+        let c = ArcLength::new(b, (), 1e-6);
+        assert!((c.length() - 1.0).abs() < 1e-6);
+        assert!(checku(&c));
+        for &s in &[0.1, 0.25, 0.5, 0.9] {
+            assert!((c.hu(s)[0] - s).abs() < 1e-6);
+            assert!((c.fraction(c.param(s)) - s).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn check_frenet() {
+        use std::f64::consts::PI;
+
+        let a = Circle {center: [0.0; 2], radius: 2.0};
+        for &s in &[0.0, 0.1, 0.5, 0.75] {
+            assert!((curvature(&a, (), s).unwrap() - 0.5).abs() < 1e-12);
+            let frame = frenet(&a, (), s).unwrap();
+            let p = a.hu(s);
+            assert!(frame.normal.approx_eq(&[-p[0] / 2.0, -p[1] / 2.0, 0.0], 1e-12));
+            assert!(frame.binormal.approx_eq(&[0.0, 0.0, 1.0], 1e-12));
+            assert_eq!(frame.torsion, 0.0);
+        }
+        assert_eq!(unit_tangent(&a, (), 0.0), Some([0.0, 1.0, 0.0]));
+
+        // The normal is undefined along a straight line.
+        let b = Lerp([0.0, 0.0], [3.0, 4.0]);
+        assert_eq!(curvature(&b, (), 0.5), Some(0.0));
+        assert_eq!(frenet(&b, (), 0.5), None);
+        assert_eq!(unit_tangent(&Lerp([1.0, 1.0], [1.0, 1.0]), (), 0.5), None);
+
+        // A helix with radius `1.0` and pitch `2π`, differentiated by central differences.
+        let c = Square::new(Circle {center: [0.0; 2], radius: 1.0}, Lerp(0.0, 2.0 * PI))
+            .into_diagonal()
+            .into_map(|(xy, z): ([f64; 2], f64)| [xy[0], xy[1], z]);
+        let frame = frenet(&c, ((), ()), 0.3).unwrap();
+        assert!((frame.curvature - 0.5).abs() < 1e-6);
+        assert!((frame.torsion - 0.5).abs() < 1e-4);
+        assert!(dot(frame.tangent, frame.normal).abs() < 1e-9);
+    }
+
+    #[test]
+    fn check_frenet_combinators() {
+        use easing::*;
+
+        let close = |a: Option<f64>, b: f64, eps: f64| (a.unwrap() - b).abs() < eps;
+        let a = Circle {center: [0.0; 2], radius: 1.0};
+        let b = Circle {center: [0.0; 2], radius: 2.0};
+        let c = Loft(a, b);
+        assert!(close(curvature(&c.left(), (), 0.5), 0.0, 1e-12));
+        assert!(close(curvature(&c.top(), (), 0.3), 1.0, 1e-12));
+        assert!(close(curvature(&c.top_bottom(0.5), (), 0.3), 1.0 / 1.5, 1e-12));
+        assert!(close(curvature(&Permute::new(c, [1, 0]).left(), (), 0.3), 1.0, 1e-12));
+        let d = Reflect {shape: c, axes: [true, false]};
+        assert!(close(curvature(&d.bottom(), (), 0.3), 0.5, 1e-12));
+        let e = c.along(Lerp([0.0, 0.25], [1.0, 0.25]));
+        assert!(close(curvature(&e, (), 0.3), 1.0 / 1.25, 1e-12));
+        let f = Restrict::new(c, [0.0, 0.5], [0.5, 1.0]);
+        assert!(close(curvature(&f.top(), (), 0.3), 1.0 / 1.5, 1e-12));
+        let g = LoftMany::new(vec![a, b], Blend::Linear);
+        assert!(close(curvature(&g.top_bottom(0.5), (), 0.3), 1.0 / 1.5, 1e-12));
+        let h = Glue::new::<(), [f64; 2]>(c, Loft(b, a), 1);
+        assert!(close(curvature(&h.top_bottom(0.25), (), 0.3), 1.0 / 1.5, 1e-12));
+
+        // The curvature does not depend on the speed.
+        assert!(close(curvature(&a.reparam(Quad(Ease::InOut)), (), 0.3), 1.0, 1e-12));
+        assert!(close(curvature(&a.reparam(Sine(Ease::In)), (), 0.7), 1.0, 1e-12));
+        let i = ArcLength::new(b, (), 1e-9);
+        assert!(close(curvature(&i, (), 0.3), 0.5, 1e-2));
+        assert!(unit_tangent(&i, (), 0.25).unwrap().approx_eq(&[-1.0, 0.0, 0.0], 1e-6));
+        assert!(close(curvature(&ConcatMany::new(vec![a, b]), (), 0.75), 0.5, 1e-12));
+        assert!(close(curvature(&(a * 2.0 + Const([1.0; 2])), (), 0.3), 0.5, 1e-12));
+
+        // 2D is the same as a Coons patch.
+        let left = QuadraticBezier([0.0, 0.0], [-1.0, 0.5], [0.0, 1.0]);
+        let right = Lerp([1.0, 0.0], [1.0, 1.0]);
+        let top = Lerp([0.0, 0.0], [1.0, 0.0]);
+        let bottom = Lerp([0.0, 1.0], [1.0, 1.0]);
+        let j = Coons::new(left, right, top, bottom);
+        let sides = vec![Some(left), Some(right.into()), Some(top.into()), Some(bottom.into())];
+        let k: Fill<QuadraticBezier<[f64; 2]>, [f64; 2]> = Fill::new(sides);
+        let expected = curvature(&left, (), 0.5).unwrap();
+        assert!(close(curvature(&j.left(), (), 0.5), expected, 1e-12));
+        assert!(close(curvature(&k.left(), (), 0.5), expected, 1e-12));
+        // Central differences of a map agree with the exact derivatives.
+        let expected = curvature(&Map::new(j.left_right(0.3), |p: [f64; 2]| p), (), 0.5).unwrap();
+        assert!(close(curvature(&j.left_right(0.3), (), 0.5), expected, 1e-4));
+        assert!(close(curvature(&k.left_right(0.3), (), 0.5), expected, 1e-4));
+    }
+
+    #[test]
+    fn check_surface() {
+        use std::f64::consts::PI;
+
+        // The cylinder is flat along its axis, bending away from the outward normal.
+        let a = Square::new(Circle {center: [0.0; 2], radius: 1.0}, Lerp(0.0, 10.0))
+            .into_map(|(xy, z): ([f64; 2], f64)| [xy[0], xy[1], z]);
+        for &(s, seams) in &[([0.0, 0.5], [true, false]), ([0.3, 0.2], [false; 2]),
+                             ([1.0, 1.0], [true, false]), ([0.5, 0.0], [false; 2])] {
+            let b = surface(&a, ((), ()), s).unwrap();
+            let p = a.hu(s);
+            assert!(b.normal.approx_eq(&[p[0], p[1], 0.0], 1e-6));
+            assert!(b.gaussian.abs() < 1e-6);
+            assert!((b.mean + 0.5).abs() < 1e-6);
+            assert_eq!(b.seams, seams);
+        }
+        let g = metric(&a, ((), ()), [0.5, 0.5]);
+        assert!(g.approx_eq(&[[4.0 * PI * PI, 0.0], [0.0, 100.0]], 1e-4));
+
+        // The normal of the sphere points inwards.
+        let c = Square::new(Lerp(0.0, 2.0 * PI), Lerp(0.0, PI))
+            .into_map(|(u, v): (f64, f64)| [
+                2.0 * v.sin() * u.cos(),
+                2.0 * v.sin() * u.sin(),
+                2.0 * v.cos()
+            ]);
+        let d = surface(&c, ((), ()), [0.2, 0.4]).unwrap();
+        assert!((d.gaussian - 0.25).abs() < 1e-6);
+        assert!((d.mean - 0.5).abs() < 1e-6);
+        assert!(d.metric[0][1].abs() < 1e-6);
+        assert_eq!(surface(&c, ((), ()), [1.0, 0.4]).unwrap().seams, [true, false]);
+        // The poles are degenerate.
+        assert_eq!(surface_normal(&c, ((), ()), [0.2, 0.0]), None);
+        assert!(surface(&c, ((), ()), [0.2, 1.0]).is_none());
+
+        // The apex of the cone is degenerate.
+        let e = Square::new(Circle {center: [0.0; 2], radius: 1.0}, Lerp(1.0, 0.0))
+            .into_map(|(xy, t): ([f64; 2], f64)| [xy[0] * t, xy[1] * t, 1.0 - t]);
+        assert!(surface_normal(&e, ((), ()), [0.5, 0.5]).is_some());
+        assert_eq!(surface_normal(&e, ((), ()), [0.5, 1.0]), None);
+        assert_eq!(metric(&e, ((), ()), [0.5, 1.0])[0][0], 0.0);
+
+        // The annulus between two circles is flat.
+        let f = Loft(Circle {center: [0.0; 2], radius: 1.0},
+                     Circle {center: [0.0; 2], radius: 2.0});
+        let g = surface(&f, (), [0.0, 0.5]).unwrap();
+        assert_eq!(g.normal, [0.0, 0.0, -1.0]);
+        assert_eq!((g.gaussian, g.mean, g.seams), (0.0, 0.0, [true, false]));
+
+        // Exact derivatives agree with central differences of a map.
+        let h = Coons::new(Lerp([0.0, 0.0, 0.0], [0.0, 1.0, 0.0]),
+                           Lerp([1.0, 0.0, 0.0], [1.0, 1.0, 0.0]),
+                           QuadraticBezier([0.0, 0.0, 0.0], [0.5, 0.0, 1.0], [1.0, 0.0, 0.0]),
+                           QuadraticBezier([0.0, 1.0, 0.0], [0.5, 1.0, -1.0], [1.0, 1.0, 0.0]));
+        let i = surface(&h, (), [0.3, 0.6]).unwrap();
+        let j = surface(&Map::new(h, |p: [f64; 3]| p), (), [0.3, 0.6]).unwrap();
+        assert!(i.normal.approx_eq(&j.normal, 1e-6));
+        assert!(i.gaussian < 0.0);
+        assert!((i.gaussian - j.gaussian).abs() < 1e-3);
+        assert!((i.mean - j.mean).abs() < 1e-3);
+        assert_eq!(i.seams, [false; 2]);
+    }
+
+    #[test]
+    fn check_side_constructors() {
+        let a = Square::new(Lerp(0.0, 1.0), Lerp(2.0, 3.0));
+        assert_eq!(Left(&a).hu(0.5), a.left().hu(0.5));
+        assert_eq!(Bottom(&a).hu(0.5), a.bottom().hu(0.5));
+        assert_eq!(LeftRight(&a, 0.25).hu(0.5), a.left_right(0.25).hu(0.5));
+        assert_eq!(a.into_top().hu(0.5), (0.5, 2.0));
+
+        // The named sides are tuple structs, which can be matched and converted.
+        let Left(inner) = a.left();
+        assert_eq!(inner.hu([0.5, 0.5]), a.hu([0.5, 0.5]));
+        let LeftRight(_, s) = a.left_right(0.25);
+        assert_eq!(s, 0.25);
+        let face: Face<_, 0, true> = Right(&a).into();
+        assert_eq!(face.hu(0.5), a.right().hu(0.5));
+        let side: Side<_> = Bottom(&a).into();
+        assert_eq!((side.axis, side.end), (1, true));
+        let slice: Slice<_, 1> = TopBottom(&a, 0.5).into();
+        assert_eq!(slice.hu(0.5), a.top_bottom(0.5).hu(0.5));
+    }
+
+    #[test]
+    fn check_default_input() {
+        // Default input need not be `Clone`.
+        #[derive(Default, PartialEq)]
+        struct Input;
+
+        let a = Square::new(Id, Id);
+        assert!(checku2::<_, (Input, Input), f64>(&a));
+        assert!(checkun::<_, (Input, Input), [f64; 2]>(&a));
+        assert!(checku3::<_, (Input, Input, Input), f64>(&Cube::new(Id, Id, Id)));
+    }
+
     #[test]
     fn check_circle() {
         let a = Circle {center: [0.0, 0.0], radius: 1.0};
@@ -1155,6 +2399,53 @@ mod tests {
         assert_eq!(top_c.hu(0.5), [[-1.0, 0.0], [2.0, 0.0]]);
     }
 
+    /// Quarter of a unit circle, without special cases for exact values.
+    struct QuarterArc;
+
+    impl Homotopy<()> for QuarterArc {
+        type Y = [f64; 2];
+
+        fn f(&self, _: ()) -> [f64; 2] {[1.0, 0.0]}
+        fn g(&self, _: ()) -> [f64; 2] {[0.0, 1.0]}
+        fn h(&self, _: (), s: f64) -> [f64; 2] {
+            let angle = s * std::f64::consts::PI * 0.5;
+            [angle.cos(), angle.sin()]
+        }
+    }
+
+    #[test]
+    fn check_approx_arc() {
+        assert!(!checku(&QuarterArc));
+        assert!(checku_approx(&QuarterArc, 1e-12));
+
+        let a = Square::new(QuarterArc, Lerp(0.0, 1.0));
+        assert!(!checku2(&a));
+        assert!(checku_approx(&a, 1e-12));
+        assert!(checku_report(&a, 1e-12).is_ok());
+    }
+
+    #[test]
+    fn check_report_failures() {
+        let a = Square::new(Lerp(0.0, 1.0), DiracFrom::new(|()| 0.0, |()| 2.0));
+        let a = a.map(|(x, y)| x + y);
+        assert!(checku_approx(&a, 1e-12));
+
+        let b = Compose::new(Lerp(0.0, 1.0), Translate(2.0)).into_map(|x| x * 2.0);
+        assert!(checku_report(&b, 1e-12).is_ok());
+
+        let c = Square::new(QuarterArc, Lerp(0.0, 1.0)).into_map(|(a, _)| a[0]);
+        let report = checku_report(&c, 1e-20);
+        assert_eq!(report.failures.len(), 3);
+        let failure = &report.failures[0];
+        assert_eq!(failure.side, vec![None, None]);
+        assert!(failure.end);
+        assert_eq!(failure.params, vec![1.0, 1.0]);
+        assert_eq!(failure.expected, 0.0);
+        assert!(failure.observed.abs() < 1e-12);
+        assert_eq!(report.failures[1].side, vec![Some(1.0), None]);
+        assert_eq!(report.failures[2].side, vec![None, Some(1.0)]);
+    }
+
     #[test]
     fn check_list() {
         let a = vec![Lerp(1.0, 2.0), Lerp(3.0, 4.0)];
@@ -0,0 +1,111 @@
+use super::*;
+
+/// A ruled surface between two curves.
+///
+/// Axis `0` runs along the curves and axis `1` runs linearly from the first to the second.
+/// This generalizes `sweep` to curves with any shared input.
+#[derive(Copy, Clone)]
+pub struct Loft<A, B>(pub A, pub B);
+
+impl<X, A, B, F> Homotopy<X, [F; 2]> for Loft<A, B>
+    where F: Float,
+          A: Homotopy<X, F>,
+          B: Homotopy<X, F, Y = A::Y>,
+          A::Y: VectorSpace<Scalar = F>,
+          X: Clone,
+{
+    type Y = A::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
+    fn g(&self, x: X) -> Self::Y {self.1.g(x)}
+    fn h(&self, x: X, s: [F; 2]) -> Self::Y {
+        self.0.h(x.clone(), s[0]).lerp(&self.1.h(x, s[0]), s[1])
+    }
+}
+
+/// Creates a ruled surface between two curves, see `Loft`.
+///
+/// Unlike `sweep`, the curves share their input, which can be of any type.
+pub fn loft<A, B>(a: A, b: B) -> Loft<A, B> {Loft(a, b)}
+
+/// How sections are blended by `LoftMany`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Blend {
+    /// Linear between neighbor sections, passing through every section.
+    Linear,
+    /// Bezier curve with the sections as control points,
+    /// passing through the first and last section only.
+    Bezier,
+    /// Catmull-Rom spline, passing through every section with a continuous tangent.
+    CatmullRom,
+}
+
+/// A surface through a list of curves, called sections.
+///
+/// Axis `0` runs along the sections and axis `1` runs across the sections,
+/// blending between them.
+/// Sections are passed through at equal steps of axis `1`, except for `Blend::Bezier`.
+#[derive(Clone)]
+pub struct LoftMany<T> {
+    sections: Vec<T>,
+    blend: Blend,
+}
+
+impl<T> LoftMany<T> {
+    /// Creates a new surface through a list of sections.
+    ///
+    /// Panics if the list is empty.
+    pub fn new(sections: Vec<T>, blend: Blend) -> Self {
+        assert!(!sections.is_empty());
+        LoftMany {sections, blend}
+    }
+
+    /// Gets the sections.
+    pub fn sections(&self) -> &[T] {&self.sections}
+
+    /// Gets the blending across sections.
+    pub fn blend(&self) -> Blend {self.blend}
+}
+
+impl<X, T, F> Homotopy<X, [F; 2]> for LoftMany<T>
+    where F: Float, T: Homotopy<X, F>, T::Y: VectorSpace<Scalar = F>, X: Clone
+{
+    type Y = T::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.sections[0].f(x)}
+    fn g(&self, x: X) -> Self::Y {self.sections[self.sections.len() - 1].g(x)}
+    fn h(&self, x: X, s: [F; 2]) -> Self::Y {
+        let [u, v] = s;
+        let c = F::from_f64;
+        let mut p: Vec<T::Y> = self.sections.iter().map(|t| t.h(x.clone(), u)).collect();
+        let n = p.len();
+        if n == 1 {return p.swap_remove(0)};
+        if v == F::one() {return p.swap_remove(n - 1)};
+
+        match self.blend {
+            Blend::Linear => {
+                let i = ((v.to_f64() * (n - 1) as f64) as usize).min(n - 2);
+                p[i].lerp(&p[i + 1], v * c((n - 1) as f64) - c(i as f64))
+            }
+            Blend::Bezier => {
+                // De Casteljau's algorithm.
+                for k in (1..n).rev() {
+                    for i in 0..k {p[i] = p[i].lerp(&p[i + 1], v)}
+                }
+                p.swap_remove(0)
+            }
+            Blend::CatmullRom => {
+                let i = ((v.to_f64() * (n - 1) as f64) as usize).min(n - 2);
+                let t = v * c((n - 1) as f64) - c(i as f64);
+                // Repeat the end sections to get tangents at the ends.
+                let p0 = &p[if i == 0 {0} else {i - 1}];
+                let p3 = &p[(i + 2).min(n - 1)];
+                let (t2, t3) = (t * t, t * t * t);
+                p0.scale(c(0.5) * (-t + c(2.0) * t2 - t3))
+                    .add_vector(&p[i].scale(c(0.5) * (c(2.0) - c(5.0) * t2 + c(3.0) * t3)))
+                    .add_vector(&p[i + 1].scale(c(0.5) * (t + c(4.0) * t2 - c(3.0) * t3)))
+                    .add_vector(&p3.scale(c(0.5) * (t3 - t2)))
+            }
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use super::*;
+
+use std::ops;
+
+/// A constant homotopy, e.g. `Const(2.0)` in `a.ops() * Const(2.0)`.
+///
+/// This works for any value, e.g. a `Dual` or a vector.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Const<T>(pub T);
+
+impl<X, S, T: Clone> Homotopy<X, S> for Const<T> {
+    type Y = T;
+
+    fn f(&self, _: X) -> T {self.0.clone()}
+    fn g(&self, _: X) -> T {self.0.clone()}
+    fn h(&self, _: X, _: S) -> T {self.0.clone()}
+}
+
+/// Enables the operators `+`, `-` and `*` for any homotopy, see `Homotopy::ops`.
+///
+/// The operators are implemented for the homotopies of this crate, e.g. `a + b * 2.0`,
+/// but they can not be implemented for every homotopy at once.
+/// Other homotopies, such as references or types from other crates, are wrapped.
+/// The results, `Sum`, `Difference` and `Product`, support the operators too,
+/// such that expressions can be chained, e.g. `(a.ops() + b - c) * 2.0`.
+#[derive(Copy, Clone)]
+pub struct Ops<T>(pub T);
+
+impl<X, S, T> Homotopy<X, S> for Ops<T>
+    where T: Homotopy<X, S>
+{
+    type Y = T::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
+    fn g(&self, x: X) -> Self::Y {self.0.g(x)}
+    fn h(&self, x: X, s: S) -> Self::Y {self.0.h(x, s)}
+}
+
+/// Adds the outputs of two homotopies pointwise, see `a.ops() + b`.
+#[derive(Copy, Clone)]
+pub struct Sum<A, B>(pub A, pub B);
+
+impl<X, S, A, B> Homotopy<X, S> for Sum<A, B>
+    where A: Homotopy<X, S>, B: Homotopy<X, S, Y = A::Y>, A::Y: VectorSpace, X: Clone, S: Copy
+{
+    type Y = A::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0.f(x.clone()).add_vector(&self.1.f(x))}
+    fn g(&self, x: X) -> Self::Y {self.0.g(x.clone()).add_vector(&self.1.g(x))}
+    fn h(&self, x: X, s: S) -> Self::Y {self.0.h(x.clone(), s).add_vector(&self.1.h(x, s))}
+}
+
+/// Subtracts the outputs of two homotopies pointwise, see `a.ops() - b`.
+#[derive(Copy, Clone)]
+pub struct Difference<A, B>(pub A, pub B);
+
+impl<X, S, A, B> Homotopy<X, S> for Difference<A, B>
+    where A: Homotopy<X, S>, B: Homotopy<X, S, Y = A::Y>, A::Y: VectorSpace, X: Clone, S: Copy
+{
+    type Y = A::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0.f(x.clone()).sub_vector(&self.1.f(x))}
+    fn g(&self, x: X) -> Self::Y {self.0.g(x.clone()).sub_vector(&self.1.g(x))}
+    fn h(&self, x: X, s: S) -> Self::Y {self.0.h(x.clone(), s).sub_vector(&self.1.h(x, s))}
+}
+
+/// Scales the output of a homotopy pointwise by a scalar homotopy, see `a * b`.
+///
+/// The scalar has the scalar type of the output, and can be a constant,
+/// e.g. `a * 2.0` which is `a * Const(2.0)`.
+///
+/// Only scaling is supported, not elementwise products of two vectors,
+/// since a scalar is a vector too and the two can not be told apart.
+/// For elementwise products, use `a.zip(b).into_map(..)`.
+#[derive(Copy, Clone)]
+pub struct Product<A, B>(pub A, pub B);
+
+impl<X, S, A, B> Homotopy<X, S> for Product<A, B>
+    where A: Homotopy<X, S>,
+          A::Y: VectorSpace,
+          B: Homotopy<X, S, Y = <A::Y as VectorSpace>::Scalar>,
+          X: Clone,
+          S: Copy,
+{
+    type Y = A::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0.f(x.clone()).scale(self.1.f(x))}
+    fn g(&self, x: X) -> Self::Y {self.0.g(x.clone()).scale(self.1.g(x))}
+    fn h(&self, x: X, s: S) -> Self::Y {self.0.h(x.clone(), s).scale(self.1.h(x, s))}
+}
+
+/// The right hand side of `*`.
+///
+/// This is a homotopy, or a plain scalar which is converted into a `Const`, e.g. `a * 2.0`.
+pub trait Factor {
+    /// The scalar homotopy.
+    type Output;
+
+    /// Converts into a scalar homotopy.
+    fn into_factor(self) -> Self::Output;
+}
+
+impl Factor for f32 {
+    type Output = Const<f32>;
+
+    fn into_factor(self) -> Const<f32> {Const(self)}
+}
+
+impl Factor for f64 {
+    type Output = Const<f64>;
+
+    fn into_factor(self) -> Const<f64> {Const(self)}
+}
+
+impl<F> Factor for Dual<F> {
+    type Output = Const<Dual<F>>;
+
+    fn into_factor(self) -> Const<Dual<F>> {Const(self)}
+}
+
+impl<'a, T> Factor for &'a T {
+    type Output = &'a T;
+
+    fn into_factor(self) -> &'a T {self}
+}
+
+// Implements `+`, `-` and `*` for the homotopy types.
+macro_rules! ops {
+    ($([$($gen:tt)*] $ty:ty $(where [$($bound:tt)*])?),* $(,)?) => {$(
+        impl<Rhs, $($gen)*> ops::Add<Rhs> for $ty $(where $($bound)*)? {
+            type Output = Sum<Self, Rhs>;
+
+            fn add(self, rhs: Rhs) -> Self::Output {Sum(self, rhs)}
+        }
+
+        impl<Rhs, $($gen)*> ops::Sub<Rhs> for $ty $(where $($bound)*)? {
+            type Output = Difference<Self, Rhs>;
+
+            fn sub(self, rhs: Rhs) -> Self::Output {Difference(self, rhs)}
+        }
+
+        impl<Rhs: Factor, $($gen)*> ops::Mul<Rhs> for $ty $(where $($bound)*)? {
+            type Output = Product<Self, Rhs::Output>;
+
+            fn mul(self, rhs: Rhs) -> Self::Output {Product(self, rhs.into_factor())}
+        }
+
+        impl<$($gen)*> Factor for $ty $(where $($bound)*)? {
+            type Output = Self;
+
+            fn into_factor(self) -> Self {self}
+        }
+    )*}
+}
+
+ops!{
+    [T] Ops<T>,
+    [A, B] Sum<A, B>,
+    [A, B] Difference<A, B>,
+    [A, B] Product<A, B>,
+    [T] Const<T>,
+    [] Id,
+    [] Dirac,
+    [X, Y, F, G] DiracFrom<X, Y, F, G> where [F: Fn(X) -> Y, G: Fn(X) -> Y],
+    [X] Lerp<X>,
+    [X] QuadraticBezier<X>,
+    [X] CubicBezier<X>,
+    [T] Circle<T>,
+    [X] Translate<X>,
+    [X1, X2, H1, H2] Square<X1, X2, H1, H2>,
+    [X1, X2, X3, H1, H2, H3] Cube<X1, X2, X3, H1, H2, H3>,
+    [X1, X2, X3, X4, H1, H2, H3, H4] Cube4<X1, X2, X3, X4, H1, H2, H3, H4>,
+    [T] Inverse<T>,
+    [T, S] AsVec<T, S>,
+    [H, F, Y1, Y2] Map<H, F, Y1, Y2> where [F: Fn(Y1) -> Y2],
+    [H, F, Y1, Y2, S] SMap<H, F, Y1, Y2, S> where [S: Param, F: Fn(Y1, S::Float) -> Y2],
+    [H, F, X1, X2] Contramap<H, F, X1, X2> where [F: Fn(X2) -> X1],
+    [A, B] Zip<A, B>,
+    [H1, H2, S1, S2] Compose<H1, H2, S1, S2>,
+    [T, S] Diagonal<T, S>,
+    [T, P] Along<T, P>,
+    [T, const AXIS: usize, const END: bool] Face<T, AXIS, END>,
+    [T] Side<T>,
+    [T, const AXIS: usize, F] Slice<T, AXIS, F>,
+    [T] Left<T>,
+    [T] Right<T>,
+    [T] Top<T>,
+    [T] Bottom<T>,
+    [T] Front<T>,
+    [T] Back<T>,
+    [T] Past<T>,
+    [T] Future<T>,
+    [T, F] LeftRight<T, F>,
+    [T, F] TopBottom<T, F>,
+    [T, F] FrontBack<T, F>,
+    [T, F] PastFuture<T, F>,
+    [T, S] Restrict<T, S>,
+    [T, const N: usize] Permute<T, N>,
+    [T, const N: usize] Reflect<T, N>,
+    [A, B] Glue<A, B>,
+    [T, S] Fill<T, S>,
+    [L, R, T, B] Coons<L, R, T, B>,
+    [A, B] Loft<A, B>,
+    [T] LoftMany<T>,
+    [P, Q] Concat<P, Q>,
+    [T] ConcatMany<T>,
+    [P] Refl<P>,
+    [P, Q, R] Assoc<P, Q, R>,
+    [P] LeftUnit<P>,
+    [P] RightInverse<P>,
+    [H, P] Reparam<H, P>,
+    [H, P] ReparamN<H, P>,
+    [H] ArcLength<H>,
+    [T, Y, S] Static<T, Y, S>,
+    [T] FiniteDiff<T>,
+    [T, const AXIS: usize] Partial<T, AXIS>,
+    [] easing::Quad,
+    [] easing::Cubic,
+    [] easing::Quartic,
+    [] easing::Sine,
+    [] easing::Expo,
+    [] easing::Back,
+    [] easing::Elastic,
+    [] easing::Bounce,
+    [] easing::Steps,
+}
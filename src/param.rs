@@ -0,0 +1,125 @@
+use super::*;
+
+/// A parameter type of a homotopy, e.g. `f64` for 1D or `[f64; N]` for N-dimensional.
+///
+/// Any `Float` scalar can be used, e.g. `f32` or `[f32; N]`.
+pub trait Param: Copy {
+    /// The scalar type of each component.
+    type Float: Float;
+
+    /// The number of dimensions.
+    const DIM: usize;
+
+    /// Creates a parameter with all components set to the same value.
+    fn splat(v: Self::Float) -> Self;
+    /// Gets the component of some axis.
+    fn get(&self, axis: usize) -> Self::Float;
+    /// Creates a parameter by calling a function for each axis.
+    fn from_fn<F: FnMut(usize) -> Self::Float>(f: F) -> Self;
+}
+
+impl<T: Float> Param for T {
+    type Float = T;
+
+    const DIM: usize = 1;
+
+    fn splat(v: T) -> Self {v}
+    fn get(&self, axis: usize) -> T {
+        assert_eq!(axis, 0);
+        *self
+    }
+    fn from_fn<F: FnMut(usize) -> T>(mut f: F) -> Self {f(0)}
+}
+
+impl<T: Float, const N: usize> Param for [T; N] {
+    type Float = T;
+
+    const DIM: usize = N;
+
+    fn splat(v: T) -> Self {[v; N]}
+    fn get(&self, axis: usize) -> T {self[axis]}
+    fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {std::array::from_fn(f)}
+}
+
+/// A parameter type that has a parameter type of one dimension higher.
+pub trait Higher: Param {
+    /// The parameter type of one dimension higher.
+    type Output: Lower<Output = Self, Float = Self::Float>;
+
+    /// Inserts a component at some axis, shifting the following axes by one.
+    fn insert(self, axis: usize, v: Self::Float) -> Self::Output {
+        assert!(axis < <Self::Output as Param>::DIM);
+        <Self::Output as Param>::from_fn(|i| {
+            if i < axis {self.get(i)}
+            else if i == axis {v}
+            else {self.get(i - 1)}
+        })
+    }
+}
+
+/// A parameter type that has a parameter type of one dimension lower.
+pub trait Lower: Param {
+    /// The parameter type of one dimension lower.
+    type Output: Higher<Output = Self, Float = Self::Float>;
+
+    /// Removes the component at some axis, shifting the following axes by one.
+    fn remove(self, axis: usize) -> Self::Output {
+        assert!(axis < Self::DIM);
+        <Self::Output as Param>::from_fn(|i| {
+            if i < axis {self.get(i)}
+            else {self.get(i + 1)}
+        })
+    }
+}
+
+impl<T: Float> Higher for T {type Output = [T; 2];}
+impl<T: Float> Lower for [T; 2] {type Output = T;}
+
+macro_rules! dims {
+    ($($lower:expr => $higher:expr),* $(,)*) => {
+        $(
+            impl<T: Float> Higher for [T; $lower] {type Output = [T; $higher];}
+            impl<T: Float> Lower for [T; $higher] {type Output = [T; $lower];}
+        )*
+    };
+}
+
+dims!{
+    2 => 3,
+    3 => 4,
+    4 => 5,
+    5 => 6,
+    6 => 7,
+    7 => 8,
+    8 => 9,
+    9 => 10,
+    10 => 11,
+    11 => 12,
+    12 => 13,
+    13 => 14,
+    14 => 15,
+    15 => 16,
+}
+
+/// Joins two parameter types, e.g. `[f64; 2]` and `f64` into `[f64; 3]`.
+pub trait Join<S: Param<Float = Self::Float>>: Param {
+    /// The joined parameter type.
+    type Output: Param<Float = Self::Float>;
+
+    /// Splits a joined parameter into its two parts.
+    fn split(s: Self::Output) -> (Self, S) {
+        (Self::from_fn(|i| s.get(i)), S::from_fn(|i| s.get(Self::DIM + i)))
+    }
+}
+
+impl<T: Float, S1: Higher<Float = T>> Join<T> for S1 {
+    type Output = S1::Output;
+}
+
+impl<T: Float, S1, const N: usize> Join<[T; N]> for S1
+    where S1: Higher<Float = T>,
+          [T; N]: Lower<Float = T>,
+          S1::Output: Join<<[T; N] as Lower>::Output>
+{
+    type Output = <S1::Output as Join<<[T; N] as Lower>::Output>>::Output;
+}
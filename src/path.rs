@@ -0,0 +1,188 @@
+use super::*;
+
+/// Concatenates two paths, running the first on `[0.0, 0.5]` and the second on `[0.5, 1.0]`.
+///
+/// The end of the first path should be the start of the second path,
+/// see `Concat::check_endpoints`.
+#[derive(Copy, Clone)]
+pub struct Concat<P, Q>(pub P, pub Q);
+
+impl<P, Q> Concat<P, Q> {
+    /// Creates a new concatenation, checking that the endpoints agree
+    /// within tolerance `eps` for some input `x`.
+    pub fn try_new<X, Y>(p: P, q: Q, x: X, eps: f64) -> Result<Self, Mismatch<Y>>
+        where P: Homotopy<X, Y = Y>, Q: Homotopy<X, Y = Y>, Y: ApproxEq, X: Clone
+    {
+        let concat = Concat(p, q);
+        concat.check_endpoints(x, eps)?;
+        Ok(concat)
+    }
+
+    /// Checks that the end of the first path is the start of the second path
+    /// within tolerance `eps` for some input `x`.
+    pub fn check_endpoints<X, Y>(&self, x: X, eps: f64) -> Result<(), Mismatch<Y>>
+        where P: Homotopy<X, Y = Y>, Q: Homotopy<X, Y = Y>, Y: ApproxEq, X: Clone
+    {
+        expect_approx(vec![0.5], self.0.g(x.clone()), self.1.f(x), eps)
+    }
+}
+
+impl<X, P, Q, F> Homotopy<X, F> for Concat<P, Q>
+    where F: Float, P: Homotopy<X, F>, Q: Homotopy<X, F, Y = P::Y>
+{
+    type Y = P::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
+    fn g(&self, x: X) -> Self::Y {self.1.g(x)}
+    fn h(&self, x: X, s: F) -> Self::Y {
+        let two = F::from_f64(2.0);
+        if s < F::from_f64(0.5) {self.0.h(x, two * s)}
+        else {self.1.h(x, two * s - F::one())}
+    }
+}
+
+/// Concatenates a list of paths, each running on an equal part of `[0.0, 1.0]`.
+///
+/// The end of each path should be the start of the next path,
+/// see `ConcatMany::check_endpoints`.
+#[derive(Clone)]
+pub struct ConcatMany<T>(Vec<T>);
+
+impl<T> ConcatMany<T> {
+    /// Creates a new concatenation of paths.
+    ///
+    /// Panics if the list is empty.
+    pub fn new(paths: Vec<T>) -> Self {
+        assert!(!paths.is_empty());
+        ConcatMany(paths)
+    }
+
+    /// Creates a new concatenation of paths, checking that the endpoints agree
+    /// within tolerance `eps` for some input `x`.
+    ///
+    /// Panics if the list is empty.
+    pub fn try_new<X, Y>(paths: Vec<T>, x: X, eps: f64) -> Result<Self, Mismatch<Y>>
+        where T: Homotopy<X, Y = Y>, Y: ApproxEq, X: Clone
+    {
+        let concat = ConcatMany::new(paths);
+        concat.check_endpoints(x, eps)?;
+        Ok(concat)
+    }
+
+    /// Gets the paths.
+    pub fn paths(&self) -> &[T] {&self.0}
+
+    /// Checks that the end of each path is the start of the next path
+    /// within tolerance `eps` for some input `x`.
+    ///
+    /// Returns the first endpoints that do not agree.
+    pub fn check_endpoints<X, Y>(&self, x: X, eps: f64) -> Result<(), Mismatch<Y>>
+        where T: Homotopy<X, Y = Y>, Y: ApproxEq, X: Clone
+    {
+        let n = self.0.len();
+        for (i, w) in self.0.windows(2).enumerate() {
+            let s = (i + 1) as f64 / n as f64;
+            expect_approx(vec![s], w[0].g(x.clone()), w[1].f(x.clone()), eps)?;
+        }
+        Ok(())
+    }
+}
+
+impl<X, T, F> Homotopy<X, F> for ConcatMany<T>
+    where F: Float, T: Homotopy<X, F>
+{
+    type Y = T::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0[0].f(x)}
+    fn g(&self, x: X) -> Self::Y {self.0[self.0.len() - 1].g(x)}
+    fn h(&self, x: X, s: F) -> Self::Y {
+        let n = self.0.len();
+        let i = ((s.to_f64() * n as f64) as usize).min(n - 1);
+        self.0[i].h(x, s * F::from_f64(n as f64) - F::from_f64(i as f64))
+    }
+}
+
+/// The constant path at the start of a path.
+///
+/// This is the identity of path concatenation, up to homotopy.
+#[derive(Copy, Clone)]
+pub struct Refl<P>(pub P);
+
+impl<X, P, F> Homotopy<X, F> for Refl<P>
+    where P: Homotopy<X, F>
+{
+    type Y = P::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
+    fn g(&self, x: X) -> Self::Y {self.0.f(x)}
+    fn h(&self, x: X, _: F) -> Self::Y {self.0.f(x)}
+}
+
+/// Witnesses associativity of path concatenation, `(p·q)·r ≃ p·(q·r)`.
+///
+/// The left side is `Concat(Concat(p, q), r)` and the right side is `Concat(p, Concat(q, r))`.
+/// This is done by moving the points where the paths meet.
+#[derive(Copy, Clone)]
+pub struct Assoc<P, Q, R>(pub P, pub Q, pub R);
+
+impl<X, P, Q, R, F> Homotopy<X, [F; 2]> for Assoc<P, Q, R>
+    where F: Float, P: Homotopy<X, F>, Q: Homotopy<X, F, Y = P::Y>, R: Homotopy<X, F, Y = P::Y>
+{
+    type Y = P::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
+    fn g(&self, x: X) -> Self::Y {self.2.g(x)}
+    fn h(&self, x: X, s: [F; 2]) -> Self::Y {
+        let [u, t] = s;
+        let c = F::from_f64;
+        let a = c(0.25) + c(0.25) * u;
+        let b = c(0.5) + c(0.25) * u;
+        if t < a {self.0.h(x, t / a)}
+        else if t < b {self.1.h(x, (t - a) / (b - a))}
+        else {self.2.h(x, (t - b) / (F::one() - b))}
+    }
+}
+
+/// Witnesses the left unit law of path concatenation, `refl·p ≃ p`.
+///
+/// The left side is `Concat(Refl(p), p)` and the right side is `p`.
+#[derive(Copy, Clone)]
+pub struct LeftUnit<P>(pub P);
+
+impl<X, P, F> Homotopy<X, [F; 2]> for LeftUnit<P>
+    where F: Float, P: Homotopy<X, F>
+{
+    type Y = P::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
+    fn g(&self, x: X) -> Self::Y {self.0.g(x)}
+    fn h(&self, x: X, s: [F; 2]) -> Self::Y {
+        let [u, t] = s;
+        let a = F::from_f64(0.5) * (F::one() - u);
+        if t < a {self.0.f(x)}
+        else {self.0.h(x, (t - a) / (F::one() - a))}
+    }
+}
+
+/// Witnesses the inverse law of path concatenation, `p·p⁻¹ ≃ refl`.
+///
+/// The left side is `Concat(p, Inverse(p))` and the right side is `Refl(p)`.
+/// This is done by going a shorter distance along `p` before turning back.
+#[derive(Copy, Clone)]
+pub struct RightInverse<P>(pub P);
+
+impl<X, P, F> Homotopy<X, [F; 2]> for RightInverse<P>
+    where F: Float, P: Homotopy<X, F>
+{
+    type Y = P::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
+    fn g(&self, x: X) -> Self::Y {self.0.f(x)}
+    fn h(&self, x: X, s: [F; 2]) -> Self::Y {
+        let [u, t] = s;
+        let two = F::from_f64(2.0);
+        if u == F::one() {return self.0.f(x)};
+        if t < F::from_f64(0.5) {self.0.h(x, two * t * (F::one() - u))}
+        else {self.0.h(x, (two - two * t) * (F::one() - u))}
+    }
+}
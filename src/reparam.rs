@@ -0,0 +1,131 @@
+use super::*;
+
+/// Reparametrizes a homotopy, changing its speed without changing its endpoints.
+///
+/// The scalar is passed through `P` before calling `H`,
+/// where `P` is a monotone map from `[0.0, 1.0]` to `[0.0, 1.0]`,
+/// e.g. `QuadraticBezier(0.0, 0.0, 1.0)` for ease-in.
+///
+/// The map should keep `0.0` and `1.0` fixed, see `Reparam::check_endpoints`.
+/// At `0.0` and `1.0` the homotopy is called directly, such that `f` and `g` are kept exactly.
+#[derive(Copy, Clone)]
+pub struct Reparam<H, P>(pub H, pub P);
+
+impl<H, P> Reparam<H, P> {
+    /// Creates a new reparametrization, checking that the map keeps `0.0` and `1.0` fixed
+    /// within tolerance `eps`.
+    pub fn try_new<F>(h: H, p: P, eps: f64) -> Result<Self, Mismatch<F>>
+        where F: Float + ApproxEq, P: Homotopy<(), F, Y = F>
+    {
+        let reparam = Reparam(h, p);
+        reparam.check_endpoints(eps)?;
+        Ok(reparam)
+    }
+
+    /// Checks that the map keeps `0.0` and `1.0` fixed within tolerance `eps`.
+    pub fn check_endpoints<F>(&self, eps: f64) -> Result<(), Mismatch<F>>
+        where F: Float + ApproxEq, P: Homotopy<(), F, Y = F>
+    {
+        expect_approx(vec![0.0], self.1.hu(F::zero()), F::zero(), eps)?;
+        expect_approx(vec![1.0], self.1.hu(F::one()), F::one(), eps)
+    }
+}
+
+impl<X, H, P, F> Homotopy<X, F> for Reparam<H, P>
+    where F: Float, H: Homotopy<X, F>, P: Homotopy<(), F, Y = F>
+{
+    type Y = H::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
+    fn g(&self, x: X) -> Self::Y {self.0.g(x)}
+    fn h(&self, x: X, s: F) -> Self::Y {
+        if s == F::zero() || s == F::one() {return self.0.h(x, s)};
+        self.0.h(x, self.1.hu(s))
+    }
+}
+
+/// Reparametrizes each axis of an N-dimensional homotopy independently.
+///
+/// This is the N-dimensional version of `Reparam`,
+/// where each axis has its own map from `[0.0, 1.0]` to `[0.0, 1.0]`, see `AxisMaps`.
+/// The maps are an array when they have the same type, e.g. `[Quad(Ease::In); 2]`,
+/// or a tuple when they differ, e.g. `(Quad(Ease::In), Sine(Ease::Out))`.
+/// The sides are kept exactly, since `0.0` and `1.0` are passed through directly.
+#[derive(Copy, Clone)]
+pub struct ReparamN<H, P>(pub H, pub P);
+
+impl<H, P> ReparamN<H, P> {
+    /// Creates a new reparametrization, checking that each map keeps `0.0` and `1.0` fixed
+    /// within tolerance `eps`.
+    pub fn try_new<F, const N: usize>(h: H, p: P, eps: f64) -> Result<Self, Mismatch<F>>
+        where F: Float + ApproxEq, P: AxisMaps<F, N>
+    {
+        let reparam = ReparamN(h, p);
+        reparam.check_endpoints(eps)?;
+        Ok(reparam)
+    }
+
+    /// Checks that each map keeps `0.0` and `1.0` fixed within tolerance `eps`.
+    ///
+    /// Returns the first corner on an axis where the map does not agree.
+    pub fn check_endpoints<F, const N: usize>(&self, eps: f64) -> Result<(), Mismatch<F>>
+        where F: Float + ApproxEq, P: AxisMaps<F, N>
+    {
+        for axis in 0..N {
+            for &end in &[0.0, 1.0] {
+                let params = (0..N).map(|i| if i == axis {end} else {0.0}).collect();
+                let s = F::from_f64(end);
+                expect_approx(params, self.1.map_axis(axis, s), s, eps)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<X, H, P, F, const N: usize> Homotopy<X, [F; N]> for ReparamN<H, P>
+    where F: Float, H: Homotopy<X, [F; N]>, P: AxisMaps<F, N>
+{
+    type Y = H::Y;
+
+    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
+    fn g(&self, x: X) -> Self::Y {self.0.g(x)}
+    fn h(&self, x: X, s: [F; N]) -> Self::Y {
+        self.0.h(x, std::array::from_fn(|i| {
+            if s[i] == F::zero() || s[i] == F::one() {s[i]} else {self.1.map_axis(i, s[i])}
+        }))
+    }
+}
+
+/// Maps from `[0.0, 1.0]` to `[0.0, 1.0]`, one for each of `N` axes, see `ReparamN`.
+///
+/// This is implemented for arrays of maps of the same type,
+/// and for tuples of up to four maps of different types.
+pub trait AxisMaps<F, const N: usize> {
+    /// Maps the parameter of some axis.
+    fn map_axis(&self, axis: usize, s: F) -> F;
+}
+
+impl<F, P, const N: usize> AxisMaps<F, N> for [P; N]
+    where P: Homotopy<(), F, Y = F>
+{
+    fn map_axis(&self, axis: usize, s: F) -> F {self[axis].hu(s)}
+}
+
+macro_rules! axis_maps {
+    ($n:expr; $($p:ident: $i:tt),*) => {
+        impl<F, $($p),*> AxisMaps<F, $n> for ($($p,)*)
+            where $($p: Homotopy<(), F, Y = F>),*
+        {
+            fn map_axis(&self, axis: usize, s: F) -> F {
+                match axis {
+                    $($i => self.$i.hu(s),)*
+                    _ => panic!("expected an axis less than {}, found {}", $n, axis),
+                }
+            }
+        }
+    }
+}
+
+axis_maps!(2; A: 0, B: 1);
+axis_maps!(3; A: 0, B: 1, C: 2);
+axis_maps!(4; A: 0, B: 1, C: 2, D: 3);
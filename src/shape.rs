@@ -0,0 +1,61 @@
+use super::*;
+
+/// Whether a homotopy ends where it starts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Endpoints {
+    /// The end corner is the start corner, e.g. for `Circle`.
+    Closed,
+    /// The end corner is not the start corner, e.g. for `Lerp` between different values.
+    Open,
+}
+
+/// Metadata about the shape of a homotopy.
+///
+/// This is implemented for every homotopy with a parameter type implementing `Param`,
+/// such that generic tooling can dispatch on dimension,
+/// e.g. `checkn`, `check_report` and `sample_grid`.
+pub trait Shape<X, S: Param>: Homotopy<X, S> {
+    /// The number of dimensions.
+    const DIM: usize = S::DIM;
+
+    /// Gets the number of dimensions.
+    fn dim(&self) -> usize {Self::DIM}
+
+    /// Gets whether the end corner is the start corner,
+    /// within tolerance `eps` for some input `x`.
+    fn endpoints(&self, x: X, eps: f64) -> Endpoints
+        where Self::Y: ApproxEq, X: Clone
+    {
+        if self.f(x.clone()).approx_eq(&self.g(x), eps) {Endpoints::Closed}
+        else {Endpoints::Open}
+    }
+}
+
+impl<X, S: Param, H: Homotopy<X, S>> Shape<X, S> for H {}
+
+/// Samples a homotopy on a grid over the parameter cube, for some input `x`.
+///
+/// There are `resolution + 1` samples per axis, including both ends.
+/// The first axis varies fastest.
+pub fn sample_grid<H, X, S>(h: &H, x: X, resolution: usize) -> Vec<(S, H::Y)>
+    where H: Homotopy<X, S>,
+          X: Clone,
+          S: Param,
+{
+    let res = resolution.max(1);
+    (0..(res + 1).pow(S::DIM as u32)).map(|ind| {
+        let s = S::from_fn(|i| {
+            S::Float::from_f64((ind / (res + 1).pow(i as u32) % (res + 1)) as f64 / res as f64)
+        });
+        (s, h.h(x.clone(), s))
+    }).collect()
+}
+
+/// Samples a homotopy on a grid over the parameter cube, for default input.
+pub fn sampleu_grid<H, X, S>(h: &H, resolution: usize) -> Vec<(S, H::Y)>
+    where H: Homotopy<X, S>,
+          X: Clone + Default,
+          S: Param,
+{
+    sample_grid(h, X::default(), resolution)
+}
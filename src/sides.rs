@@ -5,7 +5,7 @@ use super::*;
 /// This interpolates along all dimensions at once.
 #[derive(Copy, Clone)]
 pub struct Diagonal<T, S> {
-    shape: T,
+    pub(crate) shape: T,
     _s: PhantomData<S>,
 }
 
@@ -16,350 +16,333 @@ impl<T, S> Diagonal<T, S> {
     }
 }
 
-impl<X, T> Homotopy<X> for Diagonal<T, [f64; 2]>
-    where T: Homotopy<X, [f64; 2]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.shape.f(x)}
-    fn g(&self, x: X) -> Self::Y {self.shape.g(x)}
-    fn h(&self, x: X, s: f64) -> Self::Y {self.shape.h(x, [s; 2])}
-}
-
-impl<X, T> Homotopy<X> for Diagonal<T, [f64; 3]>
-    where T: Homotopy<X, [f64; 3]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.shape.f(x)}
-    fn g(&self, x: X) -> Self::Y {self.shape.g(x)}
-    fn h(&self, x: X, s: f64) -> Self::Y {self.shape.h(x, [s; 3])}
-}
-
-impl<X, T> Homotopy<X> for Diagonal<T, [f64; 4]>
-    where T: Homotopy<X, [f64; 4]>
+impl<X, T, S> Homotopy<X, S::Float> for Diagonal<T, S>
+    where S: Param, T: Homotopy<X, S>
 {
     type Y = T::Y;
 
     fn f(&self, x: X) -> Self::Y {self.shape.f(x)}
     fn g(&self, x: X) -> Self::Y {self.shape.g(x)}
-    fn h(&self, x: X, s: f64) -> Self::Y {self.shape.h(x, [s; 4])}
-}
-
-/// The left side of an N-dimensional homotopy, resulting in a N-1 homotopy.
-#[derive(Copy, Clone)]
-pub struct Left<T>(pub T);
-
-impl<X, T> Homotopy<X> for Left<T>
-    where T: Homotopy<X, [f64; 2]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [0.0, 1.0])}
-    fn h(&self, x: X, s: f64) -> Self::Y {self.0.h(x, [0.0, s])}
-}
-
-impl<X, T> Homotopy<X, [f64; 2]> for Left<T>
-    where T: Homotopy<X, [f64; 3]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [0.0, 1.0, 1.0])}
-    fn h(&self, x: X, s: [f64; 2]) -> Self::Y {self.0.h(x, [0.0, s[0], s[1]])}
-}
-
-impl<X, T> Homotopy<X, [f64; 3]> for Left<T>
-    where T: Homotopy<X, [f64; 4]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [0.0, 1.0, 1.0, 1.0])}
-    fn h(&self, x: X, s: [f64; 3]) -> Self::Y {self.0.h(x, [0.0, s[0], s[1], s[2]])}
-}
-
-/// The right side of an N-dimensional homotopy, resulting in a N-1 homotopy.
-#[derive(Copy, Clone)]
-pub struct Right<T>(pub T);
-
-impl<X, T> Homotopy<X> for Right<T>
-    where T: Homotopy<X, [f64; 2]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [1.0, 0.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.g(x)}
-    fn h(&self, x: X, s: f64) -> Self::Y {self.0.h(x, [1.0, s])}
-}
-
-impl<X, T> Homotopy<X, [f64; 2]> for Right<T>
-    where T: Homotopy<X, [f64; 3]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [1.0, 0.0, 0.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.g(x)}
-    fn h(&self, x: X, s: [f64; 2]) -> Self::Y {self.0.h(x, [1.0, s[0], s[1]])}
+    fn h(&self, x: X, s: S::Float) -> Self::Y {self.shape.h(x, S::splat(s))}
 }
 
-impl<X, T> Homotopy<X, [f64; 3]> for Right<T>
-    where T: Homotopy<X, [f64; 4]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [1.0, 0.0, 0.0, 0.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.g(x)}
-    fn h(&self, x: X, s: [f64; 3]) -> Self::Y {self.0.h(x, [1.0, s[0], s[1], s[2]])}
-}
-
-/// The top side of an N-dimensional homotopy, resulting in a N-1 homotopy.
-#[derive(Copy, Clone)]
-pub struct Top<T>(pub T);
-
-impl<X, T> Homotopy<X> for Top<T>
-    where T: Homotopy<X, [f64; 2]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [1.0, 0.0])}
-    fn h(&self, x: X, s: f64) -> Self::Y {self.0.h(x, [s, 0.0])}
-}
-
-impl<X, T> Homotopy<X, [f64; 2]> for Top<T>
-    where T: Homotopy<X, [f64; 3]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [1.0, 0.0, 1.0])}
-    fn h(&self, x: X, s: [f64; 2]) -> Self::Y {self.0.h(x, [s[0], 0.0, s[1]])}
-}
-
-impl<X, T> Homotopy<X, [f64; 3]> for Top<T>
-    where T: Homotopy<X, [f64; 4]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [1.0, 0.0, 1.0, 1.0])}
-    fn h(&self, x: X, s: [f64; 3]) -> Self::Y {self.0.h(x, [s[0], 0.0, s[1], s[2]])}
-}
-
-/// The bottom side of an N-dimensional homotopy, resulting in a N-1 homotopy.
+/// Walks an N-dimensional homotopy along a path through the parameter cube.
+///
+/// The path `P` produces points in the unit cube, e.g. `[f64; 2]` for a 2D homotopy.
+/// This generalizes `Diagonal` and `Slice` to curved cuts, e.g. a spiral through a surface.
+/// The endpoints follow the endpoints of the path.
 #[derive(Copy, Clone)]
-pub struct Bottom<T>(pub T);
+pub struct Along<T, P>(pub T, pub P);
 
-impl<X, T> Homotopy<X> for Bottom<T>
-    where T: Homotopy<X, [f64; 2]>
+impl<X, T, P, F> Homotopy<X, F> for Along<T, P>
+    where F: Float, P: Homotopy<(), F>, P::Y: Param, T: Homotopy<X, P::Y>
 {
     type Y = T::Y;
 
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [0.0, 1.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.g(x)}
-    fn h(&self, x: X, s: f64) -> Self::Y {self.0.h(x, [s, 1.0])}
+    fn f(&self, x: X) -> Self::Y {self.0.h(x, self.1.f(()))}
+    fn g(&self, x: X) -> Self::Y {self.0.h(x, self.1.g(()))}
+    fn h(&self, x: X, s: F) -> Self::Y {self.0.h(x, self.1.h((), s))}
 }
 
-impl<X, T> Homotopy<X, [f64; 2]> for Bottom<T>
-    where T: Homotopy<X, [f64; 3]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [0.0, 1.0, 0.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.g(x)}
-    fn h(&self, x: X, s: [f64; 2]) -> Self::Y {self.0.h(x, [s[0], 1.0, s[1]])}
-}
-
-impl<X, T> Homotopy<X, [f64; 3]> for Bottom<T>
-    where T: Homotopy<X, [f64; 4]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [0.0, 1.0, 0.0, 0.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.g(x)}
-    fn h(&self, x: X, s: [f64; 3]) -> Self::Y {self.0.h(x, [s[0], 1.0, s[1], s[2]])}
-}
-
-/// The front side of an N-dimensional homotopy, resulting in a N-1 homotopy.
+/// A side of an N-dimensional homotopy, resulting in a N-1 homotopy.
+///
+/// The side is selected by an axis and whether it is at the end (`1.0`) or the start (`0.0`).
+/// For example, `Face<T, 0, false>` is the left side.
+///
+/// The named sides, e.g. `Left`, are separate types that convert into `Face` and `Side`.
 #[derive(Copy, Clone)]
-pub struct Front<T>(pub T);
+pub struct Face<T, const AXIS: usize, const END: bool>(pub T);
 
-impl<X, T> Homotopy<X, [f64; 2]> for Front<T>
-    where T: Homotopy<X, [f64; 3]>
+impl<X, T, S, const AXIS: usize, const END: bool> Homotopy<X, S> for Face<T, AXIS, END>
+    where S: Higher, T: Homotopy<X, S::Output>
 {
     type Y = T::Y;
 
-    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [1.0, 1.0, 0.0])}
-    fn h(&self, x: X, s: [f64; 2]) -> Self::Y {self.0.h(x, [s[0], s[1], 0.0])}
-}
-
-impl<X, T> Homotopy<X, [f64; 3]> for Front<T>
-    where T: Homotopy<X, [f64; 4]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [1.0, 1.0, 0.0, 1.0])}
-    fn h(&self, x: X, s: [f64; 3]) -> Self::Y {self.0.h(x, [s[0], s[1], 0.0, s[2]])}
+    fn f(&self, x: X) -> Self::Y {
+        if END {self.0.h(x, S::splat(S::Float::zero()).insert(AXIS, S::Float::one()))}
+        else {self.0.f(x)}
+    }
+    fn g(&self, x: X) -> Self::Y {
+        if END {self.0.g(x)}
+        else {self.0.h(x, S::splat(S::Float::one()).insert(AXIS, S::Float::zero()))}
+    }
+    fn h(&self, x: X, s: S) -> Self::Y {
+        self.0.h(x, s.insert(AXIS, if END {S::Float::one()} else {S::Float::zero()}))
+    }
 }
 
-/// The back side of an N-dimensional homotopy, resulting in a N-1 homotopy.
+/// A side of an N-dimensional homotopy, selected at runtime.
+///
+/// This is useful when iterating over all sides, see `Homotopy::sides`.
 #[derive(Copy, Clone)]
-pub struct Back<T>(pub T);
-
-impl<X, T> Homotopy<X, [f64; 2]> for Back<T>
-    where T: Homotopy<X, [f64; 3]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [0.0, 0.0, 1.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.g(x)}
-    fn h(&self, x: X, s: [f64; 2]) -> Self::Y {self.0.h(x, [s[0], s[1], 1.0])}
+pub struct Side<T> {
+    /// The N-dimensional homotopy.
+    pub shape: T,
+    /// The axis that is fixed.
+    pub axis: usize,
+    /// Whether the axis is fixed at the end (`1.0`) or the start (`0.0`).
+    pub end: bool,
 }
 
-impl<X, T> Homotopy<X, [f64; 3]> for Back<T>
-    where T: Homotopy<X, [f64; 4]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [0.0, 0.0, 1.0, 0.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.g(x)}
-    fn h(&self, x: X, s: [f64; 3]) -> Self::Y {self.0.h(x, [s[0], s[1], 1.0, s[2]])}
+impl<T, const AXIS: usize, const END: bool> From<Face<T, AXIS, END>> for Side<T> {
+    fn from(Face(shape): Face<T, AXIS, END>) -> Side<T> {Side {shape, axis: AXIS, end: END}}
 }
 
-/// The past side of an N-dimensional homotopy, resuling in a N-1 homotopy.
-#[derive(Copy, Clone)]
-pub struct Past<T>(pub T);
-
-impl<X, T> Homotopy<X, [f64; 3]> for Past<T>
-    where T: Homotopy<X, [f64; 4]>
+impl<X, T, S> Homotopy<X, S> for Side<T>
+    where S: Higher, T: Homotopy<X, S::Output>
 {
     type Y = T::Y;
 
-    fn f(&self, x: X) -> Self::Y {self.0.f(x)}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [1.0, 1.0, 1.0, 0.0])}
-    fn h(&self, x: X, s: [f64; 3]) -> Self::Y {self.0.h(x, [s[0], s[1], s[2], 0.0])}
+    fn f(&self, x: X) -> Self::Y {
+        if self.end {self.shape.h(x, S::splat(S::Float::zero()).insert(self.axis, S::Float::one()))}
+        else {self.shape.f(x)}
+    }
+    fn g(&self, x: X) -> Self::Y {
+        if self.end {self.shape.g(x)}
+        else {self.shape.h(x, S::splat(S::Float::one()).insert(self.axis, S::Float::zero()))}
+    }
+    fn h(&self, x: X, s: S) -> Self::Y {
+        self.shape.h(x, s.insert(self.axis, if self.end {S::Float::one()} else {S::Float::zero()}))
+    }
 }
 
-/// The future side of an N-dimensional homotopy, resuling in a N-1 homotopy.
+/// Intersects an N-dimensional homotopy at some value along an axis,
+/// resulting in a N-1 homotopy.
+///
+/// For example, `Slice<T, 0>` intersects from left to right.
+/// Like the named sides, the named intersections, e.g. `LeftRight`,
+/// are separate types that convert into `Slice`.
+/// The value has the scalar type `F` of the parameters.
 #[derive(Copy, Clone)]
-pub struct Future<T>(pub T);
+pub struct Slice<T, const AXIS: usize, F = f64>(pub T, pub F);
 
-impl<X, T> Homotopy<X, [f64; 3]> for Future<T>
-    where T: Homotopy<X, [f64; 4]>
+impl<X, T, S, F, const AXIS: usize> Homotopy<X, S> for Slice<T, AXIS, F>
+    where F: Float, S: Higher<Float = F>, T: Homotopy<X, S::Output>
 {
     type Y = T::Y;
 
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [0.0, 0.0, 0.0, 1.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.g(x)}
-    fn h(&self, x: X, s: [f64; 3]) -> Self::Y {self.0.h(x, [s[0], s[1], s[2], 1.0])}
-}
-
-/// Intersects from left to right.
+    fn f(&self, x: X) -> Self::Y {self.0.h(x, S::splat(F::zero()).insert(AXIS, self.1))}
+    fn g(&self, x: X) -> Self::Y {self.0.h(x, S::splat(F::one()).insert(AXIS, self.1))}
+    fn h(&self, x: X, s: S) -> Self::Y {self.0.h(x, s.insert(AXIS, self.1))}
+}
+
+macro_rules! named_faces {
+    ($($(#[$attr:meta])* $name:ident = $axis:expr, $end:expr;)*) => {$(
+        $(#[$attr])*
+        #[derive(Copy, Clone)]
+        pub struct $name<T>(pub T);
+
+        impl<X, T, S> Homotopy<X, S> for $name<T>
+            where S: Higher, T: Homotopy<X, S::Output>
+        {
+            type Y = T::Y;
+
+            fn f(&self, x: X) -> Self::Y {Homotopy::<X, S>::f(&self.face(), x)}
+            fn g(&self, x: X) -> Self::Y {Homotopy::<X, S>::g(&self.face(), x)}
+            fn h(&self, x: X, s: S) -> Self::Y {self.face().h(x, s)}
+        }
+
+        impl<T> $name<T> {
+            fn face(&self) -> Face<&T, $axis, $end> {Face(&self.0)}
+        }
+
+        impl<T> From<$name<T>> for Face<T, $axis, $end> {
+            fn from($name(shape): $name<T>) -> Self {Face(shape)}
+        }
+
+        impl<T> From<$name<T>> for Side<T> {
+            fn from($name(shape): $name<T>) -> Self {Face::<_, $axis, $end>(shape).into()}
+        }
+
+        impl<T: Lift> Lift for $name<T> {
+            type Output = $name<T::Output>;
+
+            fn lift(&self) -> Self::Output {$name(self.0.lift())}
+        }
+    )*}
+}
+
+named_faces! {
+    /// The left side of an N-dimensional homotopy, resulting in a N-1 homotopy.
+    Left = 0, false;
+    /// The right side of an N-dimensional homotopy, resulting in a N-1 homotopy.
+    Right = 0, true;
+    /// The top side of an N-dimensional homotopy, resulting in a N-1 homotopy.
+    Top = 1, false;
+    /// The bottom side of an N-dimensional homotopy, resulting in a N-1 homotopy.
+    Bottom = 1, true;
+    /// The front side of an N-dimensional homotopy, resulting in a N-1 homotopy.
+    Front = 2, false;
+    /// The back side of an N-dimensional homotopy, resulting in a N-1 homotopy.
+    Back = 2, true;
+    /// The past side of an N-dimensional homotopy, resuling in a N-1 homotopy.
+    Past = 3, false;
+    /// The future side of an N-dimensional homotopy, resuling in a N-1 homotopy.
+    Future = 3, true;
+}
+
+macro_rules! named_slices {
+    ($($(#[$attr:meta])* $name:ident = $axis:expr;)*) => {$(
+        $(#[$attr])*
+        ///
+        /// The value has the scalar type `F` of the parameters.
+        #[derive(Copy, Clone)]
+        pub struct $name<T, F = f64>(pub T, pub F);
+
+        impl<X, T, S, F> Homotopy<X, S> for $name<T, F>
+            where F: Float, S: Higher<Float = F>, T: Homotopy<X, S::Output>
+        {
+            type Y = T::Y;
+
+            fn f(&self, x: X) -> Self::Y {Homotopy::<X, S>::f(&self.slice(), x)}
+            fn g(&self, x: X) -> Self::Y {Homotopy::<X, S>::g(&self.slice(), x)}
+            fn h(&self, x: X, s: S) -> Self::Y {self.slice().h(x, s)}
+        }
+
+        impl<T, F: Copy> $name<T, F> {
+            fn slice(&self) -> Slice<&T, $axis, F> {Slice(&self.0, self.1)}
+        }
+
+        impl<T, F> From<$name<T, F>> for Slice<T, $axis, F> {
+            fn from($name(shape, s): $name<T, F>) -> Self {Slice(shape, s)}
+        }
+
+        impl<T: Lift, F: Lift> Lift for $name<T, F> {
+            type Output = $name<T::Output, F::Output>;
+
+            fn lift(&self) -> Self::Output {$name(self.0.lift(), self.1.lift())}
+        }
+    )*}
+}
+
+named_slices! {
+    /// Intersects from left to right.
+    LeftRight = 0;
+    /// Intersects from top to botttom.
+    TopBottom = 1;
+    /// Intersects from front to back.
+    FrontBack = 2;
+    /// Intersects from past to future.
+    PastFuture = 3;
+}
+
+/// Restricts an N-dimensional homotopy to a sub-box of its parameter cube,
+/// rescaled back to the unit cube.
+///
+/// For example, `Restrict::new(shape, [1.0 / 3.0; 2], [2.0 / 3.0; 2])`
+/// is the middle third of a 2D homotopy.
+/// The endpoints are the corners of the sub-box.
 #[derive(Copy, Clone)]
-pub struct LeftRight<T>(pub T, pub f64);
-
-impl<X, T> Homotopy<X> for LeftRight<T>
-    where T: Homotopy<X, [f64; 2]>
-{
-    type Y = T::Y;
+pub struct Restrict<T, S> {
+    shape: T,
+    start: S,
+    end: S,
+}
+
+impl<T, S: Param> Restrict<T, S> {
+    /// Creates a new restriction to the sub-box from `start` to `end`.
+    ///
+    /// Panics if the sub-box is not inside the unit cube,
+    /// or if `start` is greater than `end` on some axis.
+    pub fn new(shape: T, start: S, end: S) -> Self {
+        let (zero, one) = (S::Float::zero(), S::Float::one());
+        for i in 0..S::DIM {
+            let (a, b) = (start.get(i), end.get(i));
+            assert!(zero <= a && a <= b && b <= one,
+                    "expected a sub-box of the unit cube, found {:?} to {:?} on axis {}", a, b, i);
+        }
+        Restrict {shape, start, end}
+    }
 
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [self.1, 0.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [self.1, 1.0])}
-    fn h(&self, x: X, s: f64) -> Self::Y {self.0.h(x, [self.1, s])}
-}
+    /// Gets the N-dimensional homotopy.
+    pub fn shape(&self) -> &T {&self.shape}
 
-impl<X, T> Homotopy<X, [f64; 2]> for LeftRight<T>
-    where T: Homotopy<X, [f64; 3]>
-{
-    type Y = T::Y;
+    /// Gets the start corner of the sub-box.
+    pub fn start(&self) -> S {self.start}
 
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [self.1, 0.0, 0.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [self.1, 1.0, 1.0])}
-    fn h(&self, x: X, s: [f64; 2]) -> Self::Y {self.0.h(x, [self.1, s[0], s[1]])}
+    /// Gets the end corner of the sub-box.
+    pub fn end(&self) -> S {self.end}
 }
 
-impl<X, T> Homotopy<X, [f64; 3]> for LeftRight<T>
-    where T: Homotopy<X, [f64; 4]>
+impl<X, T, S> Homotopy<X, S> for Restrict<T, S>
+    where S: Param, T: Homotopy<X, S>
 {
     type Y = T::Y;
 
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [self.1, 0.0, 0.0, 0.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [self.1, 1.0, 1.0, 1.0])}
-    fn h(&self, x: X, s: [f64; 3]) -> Self::Y {self.0.h(x, [self.1, s[0], s[1], s[2]])}
+    fn f(&self, x: X) -> Self::Y {self.shape.h(x, self.start)}
+    fn g(&self, x: X) -> Self::Y {self.shape.h(x, self.end)}
+    fn h(&self, x: X, s: S) -> Self::Y {
+        // Interpolate such that the corners are exact.
+        self.shape.h(x, S::from_fn(|i| {
+            (S::Float::one() - s.get(i)) * self.start.get(i) + s.get(i) * self.end.get(i)
+        }))
+    }
 }
 
-/// Intersects from top to botttom.
+/// Permutes the axes of an N-dimensional homotopy.
+///
+/// Axis `i` of the result is axis `axes[i]` of the shape,
+/// e.g. `[1, 0]` swaps the two parameters of a 2D homotopy.
 #[derive(Copy, Clone)]
-pub struct TopBottom<T>(pub T, pub f64);
-
-impl<X, T> Homotopy<X> for TopBottom<T>
-    where T: Homotopy<X, [f64; 2]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [0.0, self.1])}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [1.0, self.1])}
-    fn h(&self, x: X, s: f64) -> Self::Y {self.0.h(x, [s, self.1])}
-}
+pub struct Permute<T, const N: usize> {
+    shape: T,
+    axes: [usize; N],
+}
+
+impl<T, const N: usize> Permute<T, N> {
+    /// Creates a new permutation of axes.
+    ///
+    /// Panics if the axes are not a permutation of `0..N`.
+    pub fn new(shape: T, axes: [usize; N]) -> Self {
+        let mut seen = [false; N];
+        for &axis in &axes {
+            assert!(axis < N && !seen[axis], "expected a permutation, found {:?}", axes);
+            seen[axis] = true;
+        }
+        Permute {shape, axes}
+    }
 
-impl<X, T> Homotopy<X, [f64; 2]> for TopBottom<T>
-    where T: Homotopy<X, [f64; 3]>
-{
-    type Y = T::Y;
+    /// Gets the N-dimensional homotopy.
+    pub fn shape(&self) -> &T {&self.shape}
 
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [0.0, self.1, 0.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [1.0, self.1, 1.0])}
-    fn h(&self, x: X, s: [f64; 2]) -> Self::Y {self.0.h(x, [s[0], self.1, s[1]])}
+    /// Gets the permutation of axes.
+    pub fn axes(&self) -> [usize; N] {self.axes}
 }
 
-impl<X, T> Homotopy<X, [f64; 3]> for TopBottom<T>
-    where T: Homotopy<X, [f64; 4]>
+impl<X, T, F, const N: usize> Homotopy<X, [F; N]> for Permute<T, N>
+    where F: Float, T: Homotopy<X, [F; N]>
 {
     type Y = T::Y;
 
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [0.0, self.1, 0.0, 0.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [1.0, self.1, 1.0, 1.0])}
-    fn h(&self, x: X, s: [f64; 3]) -> Self::Y {self.0.h(x, [s[0], self.1, s[1], s[2]])}
+    fn f(&self, x: X) -> Self::Y {self.shape.f(x)}
+    fn g(&self, x: X) -> Self::Y {self.shape.g(x)}
+    fn h(&self, x: X, s: [F; N]) -> Self::Y {
+        let mut t = [F::zero(); N];
+        for (i, &axis) in self.axes.iter().enumerate() {t[axis] = s[i]}
+        self.shape.h(x, t)
+    }
 }
 
-/// Intersects from front to back.
+/// Reflects some axes of an N-dimensional homotopy, using `1.0 - s` for each reflected axis.
+///
+/// This generalizes `Inverse`, which reflects the only axis of a 1D homotopy.
+/// The endpoints are the corners of the shape where the reflected axes are at the opposite end.
 #[derive(Copy, Clone)]
-pub struct FrontBack<T>(pub T, pub f64);
-
-impl<X, T> Homotopy<X, [f64; 2]> for FrontBack<T>
-    where T: Homotopy<X, [f64; 3]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [0.0, 0.0, self.1])}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [1.0, 1.0, self.1])}
-    fn h(&self, x: X, s: [f64; 2]) -> Self::Y {self.0.h(x, [s[0], s[1], self.1])}
+pub struct Reflect<T, const N: usize> {
+    /// The N-dimensional homotopy.
+    pub shape: T,
+    /// Whether each axis is reflected.
+    pub axes: [bool; N],
 }
 
-impl<X, T> Homotopy<X, [f64; 3]> for FrontBack<T>
-    where T: Homotopy<X, [f64; 4]>
+impl<X, T, F, const N: usize> Homotopy<X, [F; N]> for Reflect<T, N>
+    where F: Float, T: Homotopy<X, [F; N]>
 {
     type Y = T::Y;
 
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [0.0, 0.0, self.1, 0.0])}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [1.0, 1.0, self.1, 1.0])}
-    fn h(&self, x: X, s: [f64; 3]) -> Self::Y {self.0.h(x, [s[0], s[1], self.1, s[2]])}
-}
-
-/// Intersects from past to future.
-#[derive(Copy, Clone)]
-pub struct PastFuture<T>(pub T, pub f64);
-
-impl<X, T> Homotopy<X, [f64; 3]> for PastFuture<T>
-    where T: Homotopy<X, [f64; 4]>
-{
-    type Y = T::Y;
-
-    fn f(&self, x: X) -> Self::Y {self.0.h(x, [0.0, 0.0, 0.0, self.1])}
-    fn g(&self, x: X) -> Self::Y {self.0.h(x, [1.0, 1.0, 1.0, self.1])}
-    fn h(&self, x: X, s: [f64; 3]) -> Self::Y {self.0.h(x, [s[0], s[1], s[2], self.1])}
+    fn f(&self, x: X) -> Self::Y {self.h(x, [F::zero(); N])}
+    fn g(&self, x: X) -> Self::Y {self.h(x, [F::one(); N])}
+    fn h(&self, x: X, s: [F; N]) -> Self::Y {
+        self.shape.h(x, std::array::from_fn(|i| if self.axes[i] {F::one() - s[i]} else {s[i]}))
+    }
 }
@@ -0,0 +1,122 @@
+use super::*;
+
+/// The tolerance below which the area element of a surface is treated as zero.
+///
+/// The area element is the area of the parallelogram spanned by the partial derivatives.
+/// This is also the distance below which the ends of a parameter line are treated as closed,
+/// see `Surface::seams`.
+pub const SURFACE_EPS: f64 = 1e-9;
+
+/// The geometry of a surface at some point, see `surface`.
+///
+/// Surfaces with 2D points are embedded in the plane `z = 0`, like curves, see `Frenet`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Surface {
+    /// The partial derivatives along axis `0` and `1`.
+    pub partials: [[f64; 3]; 2],
+    /// The unit normal, which is the normalized cross product of the partial derivatives.
+    pub normal: [f64; 3],
+    /// The metric tensor, also called the first fundamental form.
+    pub metric: [[f64; 2]; 2],
+    /// The second fundamental form, which is the second partial derivatives along the normal.
+    pub second_form: [[f64; 2]; 2],
+    /// The Gaussian curvature, which is the product of the principal curvatures.
+    ///
+    /// This is positive for a sphere, zero for a cylinder and negative for a saddle.
+    pub gaussian: f64,
+    /// The mean curvature, which is the mean of the principal curvatures.
+    ///
+    /// This is positive when the surface bends towards the normal.
+    pub mean: f64,
+    /// Whether the point is on a seam across axis `0` and `1`.
+    ///
+    /// A seam is where a parameter line closes on itself,
+    /// e.g. at the start and the end of the circles of a cylinder,
+    /// such that the point also belongs to the opposite side.
+    /// The geometry is regular there, but derivatives across the seam are one-sided,
+    /// so they only agree with the other side when the surface is smooth across it.
+    pub seams: [bool; 2],
+}
+
+/// Gets the metric tensor of a surface, also called the first fundamental form.
+///
+/// The determinant is zero at degenerate points, see `surface`.
+pub fn metric<H, X, const N: usize>(h: &H, x: X, s: [f64; 2]) -> [[f64; 2]; 2]
+    where for<'a> Partial<&'a H, 0>: Homotopy<X, [f64; 2], Y = [f64; N]>,
+          for<'a> Partial<&'a H, 1>: Homotopy<X, [f64; 2], Y = [f64; N]>,
+          X: Clone,
+{
+    metric_of(partials(h, x, s))
+}
+
+/// Gets the unit normal of a surface.
+///
+/// Returns `None` at degenerate points, see `surface`.
+pub fn surface_normal<H, X, const N: usize>(h: &H, x: X, s: [f64; 2]) -> Option<[f64; 3]>
+    where for<'a> Partial<&'a H, 0>: Homotopy<X, [f64; 2], Y = [f64; N]>,
+          for<'a> Partial<&'a H, 1>: Homotopy<X, [f64; 2], Y = [f64; N]>,
+          X: Clone,
+{
+    let [du, dv] = partials(h, x, s);
+    let c = cross(du, dv);
+    let area = norm(c);
+    if area <= SURFACE_EPS {None} else {Some(scale(c, 1.0 / area))}
+}
+
+/// Gets the geometry of a surface, using exact derivatives where available.
+///
+/// Returns `None` at degenerate points, where the surface collapses to a curve or a point,
+/// e.g. the apex of a cone or the poles of a sphere, since the normal is undefined.
+/// Seams are regular, but flagged, see `Surface::seams`.
+pub fn surface<H, X, const N: usize>(h: &H, x: X, s: [f64; 2]) -> Option<Surface>
+    where H: Homotopy<X, [f64; 2], Y = [f64; N]>,
+          for<'a> Partial<&'a H, 0>: Homotopy<X, [f64; 2], Y = [f64; N]>,
+          for<'a> Partial<&'a H, 1>: Homotopy<X, [f64; 2], Y = [f64; N]>,
+          for<'a> Partial<Partial<&'a H, 0>, 0>: Homotopy<X, [f64; 2], Y = [f64; N]>,
+          for<'a> Partial<Partial<&'a H, 0>, 1>: Homotopy<X, [f64; 2], Y = [f64; N]>,
+          for<'a> Partial<Partial<&'a H, 1>, 1>: Homotopy<X, [f64; 2], Y = [f64; N]>,
+          X: Clone,
+{
+    let partials = partials(h, x.clone(), s);
+    let c = cross(partials[0], partials[1]);
+    let area = norm(c);
+    if area <= SURFACE_EPS {return None};
+
+    let normal = scale(c, 1.0 / area);
+    let duu = dot(embed(Partial::<_, 0>(Partial::<_, 0>(h)).h(x.clone(), s)), normal);
+    let duv = dot(embed(Partial::<_, 1>(Partial::<_, 0>(h)).h(x.clone(), s)), normal);
+    let dvv = dot(embed(Partial::<_, 1>(Partial::<_, 1>(h)).h(x.clone(), s)), normal);
+    let metric = metric_of(partials);
+    let second_form = [[duu, duv], [duv, dvv]];
+
+    let [[e, f], [_, g]] = metric;
+    let det = e * g - f * f;
+    Some(Surface {
+        partials,
+        normal,
+        metric,
+        second_form,
+        gaussian: (duu * dvv - duv * duv) / det,
+        mean: (e * dvv - 2.0 * f * duv + g * duu) / (2.0 * det),
+        seams: [0, 1].map(|i| {
+            if s[i] != 0.0 && s[i] != 1.0 {return false};
+            let (mut start, mut end) = (s, s);
+            start[i] = 0.0;
+            end[i] = 1.0;
+            h.h(x.clone(), start).approx_eq(&h.h(x.clone(), end), SURFACE_EPS)
+        }),
+    })
+}
+
+/// Gets the partial derivatives, embedded in 3D.
+fn partials<H, X, const N: usize>(h: &H, x: X, s: [f64; 2]) -> [[f64; 3]; 2]
+    where for<'a> Partial<&'a H, 0>: Homotopy<X, [f64; 2], Y = [f64; N]>,
+          for<'a> Partial<&'a H, 1>: Homotopy<X, [f64; 2], Y = [f64; N]>,
+          X: Clone,
+{
+    [embed(Partial::<_, 0>(h).h(x.clone(), s)), embed(Partial::<_, 1>(h).h(x, s))]
+}
+
+fn metric_of([du, dv]: [[f64; 3]; 2]) -> [[f64; 2]; 2] {
+    [[dot(du, du), dot(du, dv)], [dot(dv, du), dot(dv, dv)]]
+}
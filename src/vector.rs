@@ -0,0 +1,123 @@
+use super::*;
+
+/// A vector space over a `Float` scalar, e.g. `f64`.
+///
+/// This is used by shapes that interpolate between values,
+/// e.g. `Lerp`, `QuadraticBezier`, `CubicBezier`, `Translate` and `Circle`.
+/// The scalar is also the parameter type of such shapes,
+/// e.g. `Lerp<[f32; 2]>` is a homotopy over `f32`.
+pub trait VectorSpace: Clone {
+    /// The scalar type.
+    type Scalar: Float;
+
+    /// Adds two vectors.
+    fn add_vector(&self, other: &Self) -> Self;
+    /// Multiplies a vector by a scalar.
+    fn scale(&self, s: Self::Scalar) -> Self;
+
+    /// Subtracts a vector.
+    fn sub_vector(&self, other: &Self) -> Self {self.add_vector(&other.scale(-Self::Scalar::one()))}
+    /// Linear interpolation, returning `self` at `0.0` and `other` at `1.0`.
+    fn lerp(&self, other: &Self, s: Self::Scalar) -> Self {
+        self.scale(Self::Scalar::one() - s).add_vector(&other.scale(s))
+    }
+}
+
+impl<T: Float> VectorSpace for T {
+    type Scalar = T;
+
+    fn add_vector(&self, other: &T) -> T {*self + *other}
+    fn scale(&self, s: T) -> T {*self * s}
+    fn sub_vector(&self, other: &T) -> T {*self - *other}
+}
+
+impl<T: VectorSpace, const N: usize> VectorSpace for [T; N] {
+    type Scalar = T::Scalar;
+
+    fn add_vector(&self, other: &[T; N]) -> [T; N] {
+        std::array::from_fn(|i| self[i].add_vector(&other[i]))
+    }
+    fn scale(&self, s: T::Scalar) -> [T; N] {
+        std::array::from_fn(|i| self[i].scale(s))
+    }
+    fn sub_vector(&self, other: &[T; N]) -> [T; N] {
+        std::array::from_fn(|i| self[i].sub_vector(&other[i]))
+    }
+}
+
+impl<T: VectorSpace> VectorSpace for Vec<T> {
+    type Scalar = T::Scalar;
+
+    fn add_vector(&self, other: &Vec<T>) -> Vec<T> {
+        assert_eq!(self.len(), other.len());
+        self.iter().zip(other.iter()).map(|(a, b)| a.add_vector(b)).collect()
+    }
+    fn scale(&self, s: T::Scalar) -> Vec<T> {
+        self.iter().map(|a| a.scale(s)).collect()
+    }
+    fn sub_vector(&self, other: &Vec<T>) -> Vec<T> {
+        assert_eq!(self.len(), other.len());
+        self.iter().zip(other.iter()).map(|(a, b)| a.sub_vector(b)).collect()
+    }
+}
+
+impl<A, B> VectorSpace for (A, B)
+    where A: VectorSpace, B: VectorSpace<Scalar = A::Scalar>
+{
+    type Scalar = A::Scalar;
+
+    fn add_vector(&self, other: &(A, B)) -> (A, B) {
+        (self.0.add_vector(&other.0), self.1.add_vector(&other.1))
+    }
+    fn scale(&self, s: A::Scalar) -> (A, B) {
+        (self.0.scale(s), self.1.scale(s))
+    }
+    fn sub_vector(&self, other: &(A, B)) -> (A, B) {
+        (self.0.sub_vector(&other.0), self.1.sub_vector(&other.1))
+    }
+}
+
+impl<A, B, C> VectorSpace for (A, B, C)
+    where A: VectorSpace, B: VectorSpace<Scalar = A::Scalar>, C: VectorSpace<Scalar = A::Scalar>
+{
+    type Scalar = A::Scalar;
+
+    fn add_vector(&self, other: &(A, B, C)) -> (A, B, C) {
+        (self.0.add_vector(&other.0), self.1.add_vector(&other.1), self.2.add_vector(&other.2))
+    }
+    fn scale(&self, s: A::Scalar) -> (A, B, C) {
+        (self.0.scale(s), self.1.scale(s), self.2.scale(s))
+    }
+    fn sub_vector(&self, other: &(A, B, C)) -> (A, B, C) {
+        (self.0.sub_vector(&other.0), self.1.sub_vector(&other.1), self.2.sub_vector(&other.2))
+    }
+}
+
+impl<A, B, C, D> VectorSpace for (A, B, C, D)
+    where A: VectorSpace,
+          B: VectorSpace<Scalar = A::Scalar>,
+          C: VectorSpace<Scalar = A::Scalar>,
+          D: VectorSpace<Scalar = A::Scalar>,
+{
+    type Scalar = A::Scalar;
+
+    fn add_vector(&self, other: &(A, B, C, D)) -> (A, B, C, D) {
+        (
+            self.0.add_vector(&other.0),
+            self.1.add_vector(&other.1),
+            self.2.add_vector(&other.2),
+            self.3.add_vector(&other.3),
+        )
+    }
+    fn scale(&self, s: A::Scalar) -> (A, B, C, D) {
+        (self.0.scale(s), self.1.scale(s), self.2.scale(s), self.3.scale(s))
+    }
+    fn sub_vector(&self, other: &(A, B, C, D)) -> (A, B, C, D) {
+        (
+            self.0.sub_vector(&other.0),
+            self.1.sub_vector(&other.1),
+            self.2.sub_vector(&other.2),
+            self.3.sub_vector(&other.3),
+        )
+    }
+}